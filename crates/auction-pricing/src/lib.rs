@@ -0,0 +1,641 @@
+//! Dutch auction price arithmetic
+//!
+//! The `dutch-auction` program and the off-chain simulator both call these functions, so a
+//! price computed off-chain is bit-for-bit the price a bid would pay on-chain. Every function
+//! returns `None` on overflow; the program maps that to `ErrorCode::MathOverflow`.
+
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+use core::fmt;
+use core::str::FromStr;
+
+/// Most breakpoints a piecewise-linear curve may have
+pub const MAX_BREAKPOINTS: usize = 8;
+
+/// A point the price passes through, `offset_seconds` after the auction start
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Breakpoint {
+    pub offset_seconds: u32,
+    pub price: u64,
+}
+
+/// Shape of the decay from `start_price` at the start to `reserve_price` at the end
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Curve {
+    /// Straight line from start to reserve
+    Linear,
+    /// The distance to reserve halves every `half_life_seconds`, interpolated linearly within
+    /// each half-life; reserve is reached at the end time
+    Exponential { half_life_seconds: u32 },
+    /// Drops by `step_amount` every `step_seconds`, floored at reserve
+    Stepwise { step_seconds: u32, step_amount: u64 },
+    /// Straight lines through the first `breakpoint_count` breakpoints
+    PiecewiseLinear {
+        breakpoint_count: u8,
+        breakpoints: [Breakpoint; MAX_BREAKPOINTS],
+    },
+}
+
+impl Curve {
+    /// Whether the curve can be used for an auction with these parameters. Piecewise
+    /// breakpoints must lie strictly inside the auction, in time order, with prices between
+    /// reserve and start that never increase.
+    pub fn is_valid(&self, start_price: u64, reserve_price: u64, duration_seconds: i64) -> bool {
+        match *self {
+            Curve::Linear => true,
+            Curve::Exponential { half_life_seconds } => half_life_seconds > 0,
+            Curve::Stepwise {
+                step_seconds,
+                step_amount,
+            } => step_seconds > 0 && step_amount > 0,
+            Curve::PiecewiseLinear {
+                breakpoint_count,
+                ref breakpoints,
+            } => {
+                let count = breakpoint_count as usize;
+                if count == 0 || count > MAX_BREAKPOINTS {
+                    return false;
+                }
+
+                let mut previous = Breakpoint {
+                    offset_seconds: 0,
+                    price: start_price,
+                };
+                for point in &breakpoints[..count] {
+                    if point.offset_seconds <= previous.offset_seconds
+                        || i64::from(point.offset_seconds) >= duration_seconds
+                        || point.price > previous.price
+                        || point.price < reserve_price
+                    {
+                        return false;
+                    }
+                    previous = *point;
+                }
+                true
+            }
+        }
+    }
+
+    /// The used breakpoints of a piecewise-linear curve (empty for other curves)
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        match self {
+            Curve::PiecewiseLinear {
+                breakpoint_count,
+                breakpoints,
+            } => &breakpoints[..(*breakpoint_count as usize).min(MAX_BREAKPOINTS)],
+            _ => &[],
+        }
+    }
+}
+
+/// Textual form used by the CLI and simulator: `linear`, `exponential:HALF_LIFE`,
+/// `stepwise:SECONDS:AMOUNT` or `piecewise:OFFSET@PRICE,OFFSET@PRICE,...`
+impl FromStr for Curve {
+    type Err = ParseCurveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s.split_once(':').unwrap_or((s, ""));
+
+        match (kind.trim(), params) {
+            ("linear", "") => Ok(Curve::Linear),
+            ("exponential", half_life) => Ok(Curve::Exponential {
+                half_life_seconds: parse_number(half_life)?,
+            }),
+            ("stepwise", params) => {
+                let (step_seconds, step_amount) = params.split_once(':').ok_or(ParseCurveError)?;
+                Ok(Curve::Stepwise {
+                    step_seconds: parse_number(step_seconds)?,
+                    step_amount: parse_number(step_amount)?,
+                })
+            }
+            ("piecewise", points) => {
+                let mut breakpoints = [Breakpoint::default(); MAX_BREAKPOINTS];
+                let mut count = 0;
+                for point in points.split(',') {
+                    let (offset, price) = point.split_once('@').ok_or(ParseCurveError)?;
+                    *breakpoints.get_mut(count).ok_or(ParseCurveError)? = Breakpoint {
+                        offset_seconds: parse_number(offset)?,
+                        price: parse_number(price)?,
+                    };
+                    count += 1;
+                }
+                Ok(Curve::PiecewiseLinear {
+                    breakpoint_count: count as u8,
+                    breakpoints,
+                })
+            }
+            _ => Err(ParseCurveError),
+        }
+    }
+}
+
+impl fmt::Display for Curve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Curve::Linear => write!(f, "linear"),
+            Curve::Exponential { half_life_seconds } => {
+                write!(f, "exponential:{}", half_life_seconds)
+            }
+            Curve::Stepwise {
+                step_seconds,
+                step_amount,
+            } => write!(f, "stepwise:{}:{}", step_seconds, step_amount),
+            Curve::PiecewiseLinear { .. } => {
+                write!(f, "piecewise:")?;
+                for (i, point) in self.breakpoints().iter().enumerate() {
+                    let separator = if i == 0 { "" } else { "," };
+                    write!(f, "{}{}@{}", separator, point.offset_seconds, point.price)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseCurveError;
+
+impl fmt::Display for ParseCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected linear, exponential:HALF_LIFE, stepwise:SECONDS:AMOUNT \
+             or piecewise:OFFSET@PRICE,..."
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCurveError {}
+
+fn parse_number<T: FromStr>(s: &str) -> Result<T, ParseCurveError> {
+    s.trim().parse().map_err(|_| ParseCurveError)
+}
+
+/// Price at `current_time` on `curve`, starting at `start_price` at `start_time` and reaching
+/// `reserve_price` at `end_time`, clamped to that range outside it
+pub fn current_price(
+    curve: &Curve,
+    start_price: u64,
+    reserve_price: u64,
+    start_time: i64,
+    end_time: i64,
+    current_time: i64,
+) -> Option<u64> {
+    if current_time >= end_time {
+        return Some(reserve_price);
+    }
+
+    if current_time <= start_time {
+        return Some(start_price);
+    }
+
+    let duration = end_time.checked_sub(start_time)?;
+    let elapsed = current_time.checked_sub(start_time)?;
+    let price_range = start_price.checked_sub(reserve_price)?;
+
+    let current_price = match *curve {
+        Curve::Linear => interpolate(start_price, reserve_price, elapsed, duration)?,
+        Curve::Exponential { half_life_seconds } => {
+            let half_life = i64::from(half_life_seconds);
+            let halvings = elapsed.checked_div(half_life)?;
+            let into_half_life = elapsed.checked_rem(half_life)?;
+
+            // Distance to reserve at the last whole half-life, then halfway to the next one
+            let remaining = if halvings >= 64 {
+                0
+            } else {
+                price_range >> halvings
+            };
+            let decay = (remaining as u128)
+                .checked_mul(into_half_life as u128)?
+                .checked_div((half_life as u128).checked_mul(2)?)? as u64;
+
+            reserve_price.checked_add(remaining)?.checked_sub(decay)?
+        }
+        Curve::Stepwise {
+            step_seconds,
+            step_amount,
+        } => {
+            let steps = elapsed.checked_div(i64::from(step_seconds))?;
+            let drop = (step_amount as u128).checked_mul(steps as u128)?;
+
+            if drop >= price_range as u128 {
+                reserve_price
+            } else {
+                start_price.checked_sub(drop as u64)?
+            }
+        }
+        Curve::PiecewiseLinear { .. } => {
+            let mut from = Breakpoint {
+                offset_seconds: 0,
+                price: start_price,
+            };
+            let mut to_offset = duration;
+            let mut to_price = reserve_price;
+
+            for point in curve.breakpoints() {
+                if elapsed < i64::from(point.offset_seconds) {
+                    to_offset = i64::from(point.offset_seconds);
+                    to_price = point.price;
+                    break;
+                }
+                from = *point;
+            }
+
+            let from_offset = i64::from(from.offset_seconds);
+            interpolate(
+                from.price,
+                to_price,
+                elapsed.checked_sub(from_offset)?,
+                to_offset.checked_sub(from_offset)?,
+            )?
+        }
+    };
+
+    Some(current_price.clamp(reserve_price, start_price))
+}
+
+/// Price `elapsed` seconds into a straight segment from `from_price` to `to_price` lasting
+/// `span` seconds
+fn interpolate(from_price: u64, to_price: u64, elapsed: i64, span: i64) -> Option<u64> {
+    let price_range = from_price.checked_sub(to_price)?;
+
+    // Linear decay: price = from_price - (price_range * elapsed / span)
+    let decay = (price_range as u128)
+        .checked_mul(elapsed as u128)?
+        .checked_div(span as u128)? as u64;
+
+    from_price.checked_sub(decay)
+}
+
+/// Uniform clearing price. Prices only decay, so the lowest fill is the last
+/// filled bid and no bidder is charged more than their own `price_per_token`.
+/// With no bids the auction clears at the price at end time.
+pub fn clearing_price(participant_count: u32, lowest_fill_price: u64, end_price: u64) -> u64 {
+    if participant_count == 0 {
+        end_price
+    } else {
+        lowest_fill_price
+    }
+}
+
+/// CCT base units per whole token (9 decimals). Prices are quoted in USDC base units per
+/// whole CCT, like the carbon-credits `cct_price`, while amounts are in base units.
+pub const CCT_UNIT: u64 = 1_000_000_000;
+
+/// USDC escrowed for `token_amount` base units at `price_per_token` per whole CCT, rounded up
+pub fn bid_cost(token_amount: u64, price_per_token: u64) -> Option<u64> {
+    let cost = (token_amount as u128)
+        .checked_mul(price_per_token as u128)?
+        .div_ceil(CCT_UNIT as u128);
+    u64::try_from(cost).ok()
+}
+
+/// USDC owed back to a bidder who escrowed `total_cost` for `token_amount` tokens
+pub fn refund(token_amount: u64, total_cost: u64, clearing_price: u64) -> Option<u64> {
+    total_cost.checked_sub(bid_cost(token_amount, clearing_price)?)
+}
+
+/// USDC the auction authority may withdraw once every bid settles at `clearing_price`. Each
+/// bid keeps its own rounded-up cost, so the escrow never holds less than this.
+pub fn proceeds(tokens_sold: u64, clearing_price: u64) -> Option<u64> {
+    bid_cost(tokens_sold, clearing_price)
+}
+
+/// Basis point denominator for `max_share_bps`
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Tokens a bidder who already holds `tokens_bought` may still win. A cap of 0 means no limit;
+/// with both caps set the tighter one applies.
+pub fn bidder_allowance(
+    total_tokens: u64,
+    tokens_bought: u64,
+    max_tokens_per_bidder: u64,
+    max_share_bps: u16,
+) -> Option<u64> {
+    let mut cap = u64::MAX;
+    if max_tokens_per_bidder > 0 {
+        cap = max_tokens_per_bidder;
+    }
+    if max_share_bps > 0 {
+        let share = (total_tokens as u128)
+            .checked_mul(max_share_bps as u128)?
+            .checked_div(BPS_DENOMINATOR as u128)?;
+        cap = cap.min(u64::try_from(share).ok()?);
+    }
+
+    Some(cap.saturating_sub(tokens_bought))
+}
+
+/// Closing time after a bid at `current_time`. A bid within `window_seconds` of `end_time`
+/// keeps the auction open for at least `extension_seconds` more, but never past
+/// `curve_end_time + max_extension_seconds`. A window of 0 disables extensions.
+pub fn extended_end_time(
+    curve_end_time: i64,
+    end_time: i64,
+    current_time: i64,
+    window_seconds: u32,
+    extension_seconds: u32,
+    max_extension_seconds: u32,
+) -> Option<i64> {
+    if window_seconds == 0 || current_time < end_time.checked_sub(i64::from(window_seconds))? {
+        return Some(end_time);
+    }
+
+    let cap = curve_end_time.checked_add(i64::from(max_extension_seconds))?;
+    let extended = current_time
+        .checked_add(i64::from(extension_seconds))?
+        .min(cap);
+
+    Some(end_time.max(extended))
+}
+
+/// Outcome of a sealed-bid auction over its revealed demand
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SealedClearing {
+    /// Uniform price every winner pays
+    pub clearing_price: u64,
+    /// Tokens demanded at exactly the clearing price
+    pub marginal_demand: u64,
+    /// Tokens left for bids at exactly the clearing price, shared pro rata
+    pub marginal_supply: u64,
+    /// Upper bound on tokens sold (pro-rata rounding leaves a little dust unsold)
+    pub tokens_allocated: u64,
+}
+
+/// Uniform clearing price of a sealed-bid auction. `levels` is revealed demand as
+/// `(price, tokens)` in strictly descending price order. The price falls until demand covers
+/// `supply`; bids above the clearing price fill in full and bids at it share what is left. An
+/// undersubscribed auction clears at its lowest revealed price, or at `reserve_price` with no
+/// reveals.
+pub fn sealed_clearing(
+    levels: impl IntoIterator<Item = (u64, u64)>,
+    supply: u64,
+    reserve_price: u64,
+) -> Option<SealedClearing> {
+    let mut remaining = supply;
+    let mut clearing = SealedClearing {
+        clearing_price: reserve_price,
+        ..SealedClearing::default()
+    };
+
+    for (price, tokens) in levels {
+        if remaining == 0 {
+            break;
+        }
+
+        clearing.clearing_price = price;
+        clearing.marginal_demand = tokens;
+        clearing.marginal_supply = tokens.min(remaining);
+        remaining -= clearing.marginal_supply;
+    }
+
+    clearing.tokens_allocated = supply.checked_sub(remaining)?;
+    Some(clearing)
+}
+
+/// Tokens won by a sealed bid for `token_amount` at `price`
+pub fn pro_rata_fill(token_amount: u64, price: u64, clearing: &SealedClearing) -> Option<u64> {
+    if price > clearing.clearing_price {
+        Some(token_amount)
+    } else if price < clearing.clearing_price || clearing.marginal_demand == 0 {
+        Some(0)
+    } else {
+        Some(
+            (token_amount as u128)
+                .checked_mul(clearing.marginal_supply as u128)?
+                .checked_div(clearing.marginal_demand as u128)? as u64,
+        )
+    }
+}
+
+/// Share of an unrevealed commitment's deposit kept by the auction
+pub fn forfeit(deposit: u64, forfeit_bps: u16) -> Option<u64> {
+    let forfeited = (deposit as u128)
+        .checked_mul(forfeit_bps as u128)?
+        .checked_div(BPS_DENOMINATOR as u128)?;
+    u64::try_from(forfeited).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bid_cost_rounds_partial_usdc_up() {
+        assert_eq!(bid_cost(CCT_UNIT, 25), Some(25));
+        assert_eq!(bid_cost(2 * CCT_UNIT, 7), Some(14));
+        // A single base unit still costs a whole USDC base unit
+        assert_eq!(bid_cost(1, 1), Some(1));
+        assert_eq!(bid_cost(CCT_UNIT / 2, 3), Some(2));
+        assert_eq!(bid_cost(CCT_UNIT + 1, 10), Some(11));
+        assert_eq!(bid_cost(0, 100), Some(0));
+        assert_eq!(bid_cost(100, 0), Some(0));
+    }
+
+    #[test]
+    fn bid_cost_is_none_past_u64() {
+        assert_eq!(bid_cost(u64::MAX, CCT_UNIT), Some(u64::MAX));
+        assert_eq!(bid_cost(u64::MAX, CCT_UNIT + 1), None);
+        assert_eq!(bid_cost(u64::MAX, u64::MAX), None);
+    }
+
+    #[test]
+    fn refund_returns_the_difference_to_the_clearing_cost() {
+        assert_eq!(refund(CCT_UNIT, 100, 60), Some(40));
+        assert_eq!(refund(CCT_UNIT, 100, 100), Some(0));
+        // Both sides round up, so a half token at 3 paid 2 and settles at 2
+        assert_eq!(refund(CCT_UNIT / 2, 2, 3), Some(0));
+        // Clearing above what was escrowed cannot happen; it is reported, not wrapped
+        assert_eq!(refund(CCT_UNIT, 50, 60), None);
+        assert_eq!(refund(u64::MAX, u64::MAX, u64::MAX), None);
+    }
+
+    #[test]
+    fn proceeds_never_exceed_the_escrowed_costs() {
+        assert_eq!(proceeds(10 * CCT_UNIT, 10), Some(100));
+        assert_eq!(proceeds(0, 10), Some(0));
+
+        // Two half-token bids at 3 escrow 2 each but settle for 3 together
+        let escrowed = 2 * bid_cost(CCT_UNIT / 2, 3).unwrap();
+        assert_eq!(escrowed, 4);
+        assert_eq!(proceeds(CCT_UNIT, 3), Some(3));
+        assert_eq!(proceeds(u64::MAX, u64::MAX), None);
+    }
+
+    fn price_at(curve: Curve, current_time: i64) -> Option<u64> {
+        current_price(&curve, 100, 10, 0, 100, current_time)
+    }
+
+    #[test]
+    fn prices_clamp_outside_the_auction() {
+        for curve in [
+            Curve::Linear,
+            Curve::Exponential {
+                half_life_seconds: 25,
+            },
+            Curve::Stepwise {
+                step_seconds: 10,
+                step_amount: 20,
+            },
+        ] {
+            assert_eq!(price_at(curve, -5), Some(100));
+            assert_eq!(price_at(curve, 0), Some(100));
+            assert_eq!(price_at(curve, 100), Some(10));
+            assert_eq!(price_at(curve, 200), Some(10));
+        }
+    }
+
+    #[test]
+    fn linear_price_rounds_toward_the_start_price() {
+        assert_eq!(price_at(Curve::Linear, 50), Some(55));
+        // 0.9 of decay is dropped
+        assert_eq!(price_at(Curve::Linear, 1), Some(100));
+        assert_eq!(price_at(Curve::Linear, 99), Some(11));
+    }
+
+    #[test]
+    fn exponential_price_halves_the_distance_each_half_life() {
+        let curve = Curve::Exponential {
+            half_life_seconds: 25,
+        };
+        assert_eq!(price_at(curve, 25), Some(55));
+        assert_eq!(price_at(curve, 50), Some(32));
+        assert_eq!(price_at(curve, 12), Some(79));
+        assert_eq!(price_at(curve, 99), Some(16));
+
+        // Past 64 halvings the distance is gone without shifting out of range
+        let curve = Curve::Exponential {
+            half_life_seconds: 1,
+        };
+        assert_eq!(current_price(&curve, 100, 10, 0, 1_000, 500), Some(10));
+    }
+
+    #[test]
+    fn stepwise_price_floors_at_reserve() {
+        let curve = Curve::Stepwise {
+            step_seconds: 10,
+            step_amount: 20,
+        };
+        assert_eq!(price_at(curve, 9), Some(100));
+        assert_eq!(price_at(curve, 10), Some(80));
+        assert_eq!(price_at(curve, 45), Some(20));
+        assert_eq!(price_at(curve, 50), Some(10));
+
+        let curve = Curve::Stepwise {
+            step_seconds: 1,
+            step_amount: u64::MAX,
+        };
+        assert_eq!(price_at(curve, 1), Some(10));
+    }
+
+    #[test]
+    fn piecewise_price_follows_each_segment() {
+        let curve: Curve = "piecewise:20@40".parse().unwrap();
+        assert!(curve.is_valid(100, 10, 100));
+        assert_eq!(price_at(curve, 10), Some(70));
+        assert_eq!(price_at(curve, 20), Some(40));
+        assert_eq!(price_at(curve, 60), Some(25));
+    }
+
+    #[test]
+    fn price_is_none_when_reserve_exceeds_start() {
+        assert_eq!(current_price(&Curve::Linear, 10, 100, 0, 100, 50), None);
+        // Outside the auction the clamp answers before any arithmetic
+        assert_eq!(current_price(&Curve::Linear, 10, 100, 0, 100, 0), Some(10));
+    }
+
+    #[test]
+    fn curve_validation_rejects_degenerate_shapes() {
+        let exponential = Curve::Exponential {
+            half_life_seconds: 0,
+        };
+        let stepwise = Curve::Stepwise {
+            step_seconds: 10,
+            step_amount: 0,
+        };
+        assert!(!exponential.is_valid(100, 10, 100));
+        assert!(!stepwise.is_valid(100, 10, 100));
+
+        for points in [
+            "0@50",
+            "100@50",
+            "20@150",
+            "20@5",
+            "40@50,20@30",
+            "20@40,40@60",
+        ] {
+            let curve: Curve = format!("piecewise:{}", points).parse().unwrap();
+            assert!(!curve.is_valid(100, 10, 100), "{}", points);
+        }
+    }
+
+    #[test]
+    fn curves_round_trip_through_text() {
+        for text in [
+            "linear",
+            "exponential:600",
+            "stepwise:60:5",
+            "piecewise:10@90,20@50",
+        ] {
+            let curve: Curve = text.parse().unwrap();
+            assert_eq!(curve.to_string(), text);
+        }
+        assert_eq!("linear:1".parse::<Curve>(), Err(ParseCurveError));
+        assert_eq!("stepwise:60".parse::<Curve>(), Err(ParseCurveError));
+        assert_eq!(
+            "piecewise:1@9,2@8,3@7,4@6,5@5,6@4,7@3,8@2,9@1".parse::<Curve>(),
+            Err(ParseCurveError)
+        );
+    }
+
+    #[test]
+    fn clearing_price_falls_back_to_the_end_price_without_bids() {
+        assert_eq!(clearing_price(0, 100, 30), 30);
+        assert_eq!(clearing_price(3, 45, 30), 45);
+    }
+
+    #[test]
+    fn bidder_allowance_applies_the_tighter_cap() {
+        assert_eq!(bidder_allowance(1_000, 0, 0, 0), Some(u64::MAX));
+        assert_eq!(bidder_allowance(1_000, 100, 300, 0), Some(200));
+        assert_eq!(bidder_allowance(1_000, 0, 300, 2_500), Some(250));
+        assert_eq!(bidder_allowance(1_000, 0, 200, 2_500), Some(200));
+        // Already over the cap, and a share that rounds down to nothing
+        assert_eq!(bidder_allowance(1_000, 400, 300, 0), Some(0));
+        assert_eq!(bidder_allowance(999, 0, 0, 1), Some(0));
+        assert_eq!(bidder_allowance(u64::MAX, 0, 0, 10_000), Some(u64::MAX));
+        // A share over 100% of a huge supply is reported, not truncated
+        assert_eq!(bidder_allowance(u64::MAX, 0, 0, u16::MAX), None);
+    }
+
+    #[test]
+    fn forfeit_is_none_past_u64() {
+        assert_eq!(forfeit(1_000, 2_500), Some(250));
+        assert_eq!(forfeit(999, 1), Some(0));
+        assert_eq!(forfeit(u64::MAX, 10_000), Some(u64::MAX));
+        assert_eq!(forfeit(u64::MAX, u16::MAX), None);
+    }
+
+    #[test]
+    fn extensions_only_apply_inside_the_window_and_under_the_cap() {
+        // Outside the window, or with extensions off
+        assert_eq!(extended_end_time(100, 100, 50, 10, 30, 60), Some(100));
+        assert_eq!(extended_end_time(100, 100, 95, 0, 30, 60), Some(100));
+
+        assert_eq!(extended_end_time(100, 100, 95, 10, 30, 60), Some(125));
+        assert_eq!(extended_end_time(100, 150, 145, 10, 30, 60), Some(160));
+        // Never closes earlier than already scheduled
+        assert_eq!(extended_end_time(100, 150, 145, 10, 1, 60), Some(150));
+    }
+
+    #[test]
+    fn extension_times_are_none_on_overflow() {
+        assert_eq!(extended_end_time(0, i64::MIN, 0, 1, 0, 0), None);
+        assert_eq!(
+            extended_end_time(i64::MAX, i64::MAX, i64::MAX, 1, 0, 1),
+            None
+        );
+    }
+}
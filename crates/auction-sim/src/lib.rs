@@ -0,0 +1,225 @@
+//! Dutch auction replay
+//!
+//! Replays an auction second by second against a synthetic demand curve using the same
+//! `auction_pricing` arithmetic as the `dutch-auction` program, so the reported prices, refunds
+//! and proceeds are exactly what the chain would produce for the same bids.
+
+use std::fmt;
+
+pub use auction_pricing::Curve;
+
+/// Parameters passed to `create_auction`
+#[derive(Clone, Copy, Debug)]
+pub struct AuctionParams {
+    pub total_tokens: u64,
+    pub start_price: u64,
+    pub reserve_price: u64,
+    pub duration_seconds: i64,
+    pub price_curve: Curve,
+    /// Smallest bid; the final lot may be smaller
+    pub min_bid_tokens: u64,
+    /// 0 for no limit
+    pub max_tokens_per_bidder: u64,
+    /// 0 for no limit
+    pub max_share_bps: u16,
+}
+
+/// A synthetic bidder (one wallet, one bid) who buys as soon as the price falls to `max_price`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bidder {
+    pub max_price: u64,
+    pub token_amount: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct FilledBid {
+    /// Index into the bidder list
+    pub bidder: usize,
+    /// Seconds after the auction start
+    pub offset: i64,
+    pub token_amount: u64,
+    pub price_per_token: u64,
+    pub total_cost: u64,
+    pub refund: u64,
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub bids: Vec<FilledBid>,
+    /// Seconds after the start at which the supply sold out, if it did
+    pub fill_time: Option<i64>,
+    pub clearing_price: u64,
+    pub tokens_sold: u64,
+    pub unsold_tokens: u64,
+    /// USDC escrowed by all bids
+    pub total_raised: u64,
+    /// USDC the auction authority withdraws after finalization
+    pub proceeds: u64,
+    /// USDC returned to bidders on claim
+    pub total_refunds: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimError {
+    /// The program would reject these parameters in `create_auction`
+    InvalidParams(&'static str),
+    MathOverflow,
+}
+
+impl fmt::Display for SimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimError::InvalidParams(reason) => write!(f, "invalid auction parameters: {}", reason),
+            SimError::MathOverflow => write!(f, "math overflow"),
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
+
+/// `count` bidders of `token_amount` tokens each, with limit prices spread evenly from
+/// `high` down to `low`
+pub fn linear_demand(high: u64, low: u64, count: u32, token_amount: u64) -> Vec<Bidder> {
+    let (high, low) = (high.max(low), high.min(low));
+    let steps = u64::from(count.saturating_sub(1)).max(1);
+
+    (0..u64::from(count))
+        .map(|i| Bidder {
+            max_price: high - ((high - low) as u128 * i as u128 / steps as u128) as u64,
+            token_amount,
+        })
+        .collect()
+}
+
+/// Run `place_bid` for every bidder in the first second the price reaches their limit, then
+/// `finalize_auction` and settle every claim
+pub fn simulate(params: AuctionParams, bidders: &[Bidder]) -> Result<Outcome, SimError> {
+    if params.start_price <= params.reserve_price {
+        return Err(SimError::InvalidParams(
+            "start price must exceed reserve price",
+        ));
+    }
+    if params.duration_seconds <= 0 {
+        return Err(SimError::InvalidParams("duration must be positive"));
+    }
+    if !params.price_curve.is_valid(
+        params.start_price,
+        params.reserve_price,
+        params.duration_seconds,
+    ) {
+        return Err(SimError::InvalidParams("invalid price curve"));
+    }
+    if u64::from(params.max_share_bps) > auction_pricing::BPS_DENOMINATOR {
+        return Err(SimError::InvalidParams("max share exceeds 100%"));
+    }
+
+    // Every synthetic bidder bids once, so their cap is the allowance of an empty position
+    let allowance = auction_pricing::bidder_allowance(
+        params.total_tokens,
+        0,
+        params.max_tokens_per_bidder,
+        params.max_share_bps,
+    )
+    .ok_or(SimError::MathOverflow)?;
+
+    let start_time = 0;
+    let end_time = params.duration_seconds;
+
+    // Prices only decay, so bidders enter in order of their limit price
+    let mut queue: Vec<usize> = (0..bidders.len()).collect();
+    queue.sort_by(|&a, &b| bidders[b].max_price.cmp(&bidders[a].max_price));
+    let mut queue = queue.into_iter().peekable();
+
+    let mut tokens_remaining = params.total_tokens;
+    let mut lowest_fill_price = params.start_price;
+    let mut total_raised = 0u64;
+    let mut bids = Vec::new();
+    let mut fill_time = None;
+
+    for now in start_time..end_time {
+        if tokens_remaining == 0 {
+            break;
+        }
+
+        let price = auction_pricing::current_price(
+            &params.price_curve,
+            params.start_price,
+            params.reserve_price,
+            start_time,
+            end_time,
+            now,
+        )
+        .ok_or(SimError::MathOverflow)?;
+
+        while let Some(&bidder) = queue.peek() {
+            if bidders[bidder].max_price < price || tokens_remaining == 0 {
+                break;
+            }
+            queue.next();
+
+            let token_amount = bidders[bidder]
+                .token_amount
+                .min(tokens_remaining)
+                .min(allowance);
+            if token_amount == 0
+                || (token_amount < params.min_bid_tokens && token_amount != tokens_remaining)
+            {
+                continue;
+            }
+            let total_cost =
+                auction_pricing::bid_cost(token_amount, price).ok_or(SimError::MathOverflow)?;
+
+            tokens_remaining -= token_amount;
+            total_raised = total_raised
+                .checked_add(total_cost)
+                .ok_or(SimError::MathOverflow)?;
+            lowest_fill_price = lowest_fill_price.min(price);
+            bids.push(FilledBid {
+                bidder,
+                offset: now - start_time,
+                token_amount,
+                price_per_token: price,
+                total_cost,
+                refund: 0,
+            });
+
+            if tokens_remaining == 0 {
+                fill_time = Some(now - start_time);
+            }
+        }
+    }
+
+    let end_price = auction_pricing::current_price(
+        &params.price_curve,
+        params.start_price,
+        params.reserve_price,
+        start_time,
+        end_time,
+        end_time,
+    )
+    .ok_or(SimError::MathOverflow)?;
+    let clearing_price =
+        auction_pricing::clearing_price(bids.len() as u32, lowest_fill_price, end_price);
+
+    let mut total_refunds = 0u64;
+    for bid in &mut bids {
+        bid.refund = auction_pricing::refund(bid.token_amount, bid.total_cost, clearing_price)
+            .ok_or(SimError::MathOverflow)?;
+        total_refunds += bid.refund;
+    }
+
+    let tokens_sold = params.total_tokens - tokens_remaining;
+    let proceeds =
+        auction_pricing::proceeds(tokens_sold, clearing_price).ok_or(SimError::MathOverflow)?;
+
+    Ok(Outcome {
+        bids,
+        fill_time,
+        clearing_price,
+        tokens_sold,
+        unsold_tokens: tokens_remaining,
+        total_raised,
+        proceeds,
+        total_refunds,
+    })
+}
@@ -0,0 +1,190 @@
+//! `auction-sim` - replay a Dutch auction against a synthetic demand curve
+
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Context as _, Result};
+use auction_sim::{linear_demand, simulate, AuctionParams, Bidder, Curve, Outcome};
+use clap::Parser;
+use serde_json::{json, Value};
+
+#[derive(Parser)]
+#[command(
+    name = "auction-sim",
+    version,
+    about = "Replay a Dutch auction second by second with the on-chain price arithmetic"
+)]
+struct Cli {
+    /// CCT (base units) offered
+    #[arg(long)]
+    tokens: u64,
+
+    /// Opening price (USDC base units per whole CCT)
+    #[arg(long)]
+    start_price: u64,
+
+    /// Floor price reached at the end of the auction (USDC base units per whole CCT)
+    #[arg(long)]
+    reserve_price: u64,
+
+    /// Auction length in seconds
+    #[arg(long)]
+    duration: i64,
+
+    /// Price decay: linear, exponential:HALF_LIFE, stepwise:SECONDS:AMOUNT or
+    /// piecewise:OFFSET@PRICE,...
+    #[arg(long, default_value = "linear")]
+    curve: Curve,
+
+    /// Smallest bid in CCT base units (the final lot may be smaller)
+    #[arg(long, default_value_t = 0)]
+    min_bid: u64,
+
+    /// Most CCT (base units) one bidder may win [default: no limit]
+    #[arg(long)]
+    max_per_bidder: Option<u64>,
+
+    /// Most of the supply one bidder may win, in basis points [default: no limit]
+    #[arg(long)]
+    max_share_bps: Option<u16>,
+
+    /// A bidder buying QTY CCT base units once the price reaches PRICE per CCT (repeatable)
+    #[arg(long, value_name = "PRICE:QTY")]
+    bidder: Vec<BidderSpec>,
+
+    /// COUNT bidders of QTY tokens each, limit prices spread evenly from HIGH to LOW (repeatable)
+    #[arg(long, value_name = "HIGH:LOW:COUNT:QTY")]
+    linear_demand: Vec<LinearDemand>,
+
+    /// Print the result, including every filled bid, as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Clone)]
+struct BidderSpec(Bidder);
+
+impl FromStr for BidderSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let [max_price, token_amount] = parse_fields(s)?;
+        Ok(Self(Bidder {
+            max_price,
+            token_amount,
+        }))
+    }
+}
+
+#[derive(Clone)]
+struct LinearDemand {
+    high: u64,
+    low: u64,
+    count: u32,
+    token_amount: u64,
+}
+
+impl FromStr for LinearDemand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let [high, low, count, token_amount] = parse_fields(s)?;
+        Ok(Self {
+            high,
+            low,
+            count: u32::try_from(count).context("bidder count too large")?,
+            token_amount,
+        })
+    }
+}
+
+fn parse_fields<const N: usize>(s: &str) -> Result<[u64; N]> {
+    let fields = s
+        .split(':')
+        .map(|field| field.trim().parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("invalid number in {:?}", s))?;
+
+    fields
+        .try_into()
+        .map_err(|_| anyhow!("expected {} ':'-separated fields in {:?}", N, s))
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let mut bidders: Vec<Bidder> = cli.bidder.iter().map(|spec| spec.0).collect();
+    for demand in &cli.linear_demand {
+        bidders.extend(linear_demand(
+            demand.high,
+            demand.low,
+            demand.count,
+            demand.token_amount,
+        ));
+    }
+    if bidders.is_empty() {
+        bail!("no demand: pass --bidder and/or --linear-demand");
+    }
+
+    let params = AuctionParams {
+        total_tokens: cli.tokens,
+        start_price: cli.start_price,
+        reserve_price: cli.reserve_price,
+        duration_seconds: cli.duration,
+        price_curve: cli.curve,
+        min_bid_tokens: cli.min_bid,
+        max_tokens_per_bidder: cli.max_per_bidder.unwrap_or(0),
+        max_share_bps: cli.max_share_bps.unwrap_or(0),
+    };
+    let outcome = simulate(params, &bidders)?;
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&outcome_json(&outcome))?);
+    } else {
+        print_summary(&params, &outcome);
+    }
+    Ok(())
+}
+
+fn print_summary(params: &AuctionParams, outcome: &Outcome) {
+    let fill_time = match outcome.fill_time {
+        Some(offset) => format!("{}s", offset),
+        None => format!("did not sell out ({}s)", params.duration_seconds),
+    };
+
+    println!("bids filled      {}", outcome.bids.len());
+    println!("fill time        {}", fill_time);
+    println!("clearing price   {}", outcome.clearing_price);
+    println!("tokens sold      {}", outcome.tokens_sold);
+    println!("unsold tokens    {}", outcome.unsold_tokens);
+    println!("total raised     {}", outcome.total_raised);
+    println!("proceeds         {}", outcome.proceeds);
+    println!("total refunds    {}", outcome.total_refunds);
+}
+
+fn outcome_json(outcome: &Outcome) -> Value {
+    let bids: Vec<Value> = outcome
+        .bids
+        .iter()
+        .map(|bid| {
+            json!({
+                "bidder": bid.bidder,
+                "offset": bid.offset,
+                "token_amount": bid.token_amount,
+                "price_per_token": bid.price_per_token,
+                "total_cost": bid.total_cost,
+                "refund": bid.refund,
+            })
+        })
+        .collect();
+
+    json!({
+        "fill_time": outcome.fill_time,
+        "clearing_price": outcome.clearing_price,
+        "tokens_sold": outcome.tokens_sold,
+        "unsold_tokens": outcome.unsold_tokens,
+        "total_raised": outcome.total_raised,
+        "proceeds": outcome.proceeds,
+        "total_refunds": outcome.total_refunds,
+        "bids": bids,
+    })
+}
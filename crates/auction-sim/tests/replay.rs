@@ -0,0 +1,121 @@
+use auction_sim::{linear_demand, simulate, AuctionParams, Bidder, Curve, SimError};
+
+/// Base units per whole CCT; prices are per whole CCT
+const CCT: u64 = auction_pricing::CCT_UNIT;
+
+fn params(whole_tokens: u64) -> AuctionParams {
+    AuctionParams {
+        total_tokens: whole_tokens * CCT,
+        start_price: 100,
+        reserve_price: 10,
+        duration_seconds: 1_000,
+        price_curve: Curve::Linear,
+        min_bid_tokens: 0,
+        max_tokens_per_bidder: 0,
+        max_share_bps: 0,
+    }
+}
+
+#[test]
+fn sold_out_auction_clears_at_the_filling_bid() {
+    let bidders = [
+        Bidder {
+            max_price: 91,
+            token_amount: 600 * CCT,
+        },
+        Bidder {
+            max_price: 55,
+            token_amount: 600 * CCT,
+        },
+    ];
+    let outcome = simulate(params(1_000), &bidders).unwrap();
+
+    // 100 - 90 * t / 1000 reaches 91 at t = 100 and 55 at t = 500
+    assert_eq!(outcome.fill_time, Some(500));
+    assert_eq!(outcome.clearing_price, 55);
+    assert_eq!(outcome.bids[1].token_amount, 400 * CCT);
+    assert_eq!(outcome.unsold_tokens, 0);
+    assert_eq!(outcome.total_raised, 600 * 91 + 400 * 55);
+    assert_eq!(outcome.total_refunds, 600 * (91 - 55));
+    assert_eq!(
+        outcome.proceeds + outcome.total_refunds,
+        outcome.total_raised
+    );
+}
+
+#[test]
+fn undersold_auction_keeps_unsold_supply() {
+    let outcome = simulate(params(10_000), &linear_demand(100, 20, 10, 100 * CCT)).unwrap();
+
+    assert_eq!(outcome.fill_time, None);
+    assert_eq!(outcome.bids.len(), 10);
+    assert_eq!(outcome.unsold_tokens, 9_000 * CCT);
+    assert_eq!(outcome.clearing_price, outcome.bids[9].price_per_token);
+    assert_eq!(
+        outcome.proceeds + outcome.total_refunds,
+        outcome.total_raised
+    );
+}
+
+#[test]
+fn rejects_parameters_the_program_rejects() {
+    let mut bad = params(1_000);
+    bad.reserve_price = bad.start_price;
+
+    assert!(matches!(
+        simulate(bad, &[]),
+        Err(SimError::InvalidParams(_))
+    ));
+}
+
+#[test]
+fn stepwise_curve_fills_on_step_boundaries() {
+    let mut stepwise = params(1_000);
+    stepwise.price_curve = "stepwise:60:5".parse().unwrap();
+
+    let bidders = [Bidder {
+        max_price: 83,
+        token_amount: 1_000 * CCT,
+    }];
+    let outcome = simulate(stepwise, &bidders).unwrap();
+
+    // 100, 95, 90, 85, 80: the first price at or below 83 arrives with the fourth step
+    assert_eq!(outcome.fill_time, Some(240));
+    assert_eq!(outcome.clearing_price, 80);
+}
+
+#[test]
+fn caps_and_minimum_shape_the_fills() {
+    let mut capped = params(1_000);
+    capped.min_bid_tokens = 50 * CCT;
+    capped.max_share_bps = 4_000;
+
+    let bidders = [
+        Bidder {
+            max_price: 95,
+            token_amount: 900 * CCT,
+        },
+        Bidder {
+            max_price: 90,
+            token_amount: 10 * CCT,
+        },
+        Bidder {
+            max_price: 80,
+            token_amount: 400 * CCT,
+        },
+        Bidder {
+            max_price: 70,
+            token_amount: 500 * CCT,
+        },
+    ];
+    let outcome = simulate(capped, &bidders).unwrap();
+
+    // 900 is capped at 40% of supply, the dust bid is skipped and the last lot may be small
+    let fills: Vec<(usize, u64)> = outcome
+        .bids
+        .iter()
+        .map(|bid| (bid.bidder, bid.token_amount))
+        .collect();
+    assert_eq!(fills, vec![(0, 400 * CCT), (2, 400 * CCT), (3, 200 * CCT)]);
+    assert_eq!(outcome.unsold_tokens, 0);
+}
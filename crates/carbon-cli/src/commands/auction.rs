@@ -0,0 +1,407 @@
+use anyhow::{anyhow, Result};
+use auction_pricing::Curve;
+use carbon_client::accounts::{Auction, Bid, IndustryAccount, LimitOrder, ProgramConfig};
+use carbon_client::auction::{self as builders, CreateAuctionArgs};
+use carbon_client::carbon_credits::VerificationStatus;
+use carbon_client::dutch_auction::{
+    AuctionKind, BidLimits, BidStatus, ExtensionPolicy, LimitOrderStatus, SeriesTemplate,
+};
+use carbon_client::{get_associated_token_address, pda};
+use clap::{Args, Subcommand};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::context::Context;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create a new auction batch (carbon-credits admin)
+    Create(Box<CreateArgs>),
+
+    /// Replace the parameters of a scheduled batch before it opens
+    Update(Box<AuctionArgs>),
+
+    /// Show one auction
+    Show(BatchArgs),
+
+    /// Finalize an ended or sold-out auction and fix its clearing price
+    Finalize(BatchArgs),
+
+    /// Cancel an auction that has not been finalized; bids become refundable
+    Cancel(BatchArgs),
+
+    /// Refund every pending bid of a cancelled or failed auction
+    Refund(BatchArgs),
+
+    /// List the bids placed in an auction
+    Bids(BatchArgs),
+
+    /// List the resting limit orders of an auction
+    Orders(BatchArgs),
+
+    /// Fill every open limit order whose limit the current price has reached
+    Crank(BatchArgs),
+}
+
+/// Selects an existing auction
+#[derive(Args)]
+pub struct BatchArgs {
+    batch: u32,
+
+    /// Series that launched the batch [default: a standalone batch]
+    #[arg(long)]
+    series: Option<u32>,
+}
+
+impl BatchArgs {
+    fn address(&self) -> Pubkey {
+        match self.series {
+            Some(series) => pda::series_auction(&pda::auction_series(series).0, self.batch).0,
+            None => pda::auction(self.batch).0,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct CreateArgs {
+    #[command(flatten)]
+    auction: AuctionArgs,
+
+    /// USDC account that receives proceeds [default: carbon-credits treasury]
+    #[arg(long)]
+    treasury: Option<Pubkey>,
+}
+
+#[derive(Args)]
+pub struct AuctionArgs {
+    #[arg(long)]
+    batch: u32,
+
+    /// CCT (base units) offered
+    #[arg(long)]
+    tokens: u64,
+
+    /// Opening time as a unix timestamp; a future time announces the batch as scheduled
+    /// [default: now]
+    #[arg(long, default_value_t = 0)]
+    start_time: i64,
+
+    #[command(flatten)]
+    template: TemplateArgs,
+}
+
+/// Pricing, limits and timing shared by single auctions and series templates
+#[derive(Args)]
+pub struct TemplateArgs {
+    /// Opening price (USDC base units per whole CCT)
+    #[arg(long)]
+    pub start_price: u64,
+
+    /// Floor price reached at the end of the auction (USDC base units per whole CCT)
+    #[arg(long)]
+    pub reserve_price: u64,
+
+    /// Auction length in seconds
+    #[arg(long)]
+    pub duration: i64,
+
+    /// Price decay: linear, exponential:HALF_LIFE, stepwise:SECONDS:AMOUNT or
+    /// piecewise:OFFSET@PRICE,...
+    #[arg(long, default_value = "linear")]
+    pub curve: Curve,
+
+    /// Smallest bid in CCT base units (the final lot may be smaller)
+    #[arg(long, default_value_t = 0)]
+    pub min_bid: u64,
+
+    /// Most CCT (base units) one bidder may win [default: no limit]
+    #[arg(long)]
+    pub max_per_bidder: Option<u64>,
+
+    /// Most of the supply one bidder may win, in basis points [default: no limit]
+    #[arg(long)]
+    pub max_share_bps: Option<u16>,
+
+    /// Seconds before the end in which a bid extends the auction [default: no extensions]
+    #[arg(long, default_value_t = 0)]
+    pub extension_window: u32,
+
+    /// Seconds a late bid keeps the auction open
+    #[arg(long, default_value_t = 0)]
+    pub extension: u32,
+
+    /// Most seconds the auction may run past its original end [default: --extension]
+    #[arg(long)]
+    pub max_extension: Option<u32>,
+
+    /// USDC (base units) the auction must raise, or it fails and refunds every bid
+    #[arg(long, default_value_t = 0)]
+    pub min_raise: u64,
+}
+
+impl From<&TemplateArgs> for SeriesTemplate {
+    fn from(args: &TemplateArgs) -> Self {
+        SeriesTemplate {
+            start_price: args.start_price,
+            reserve_price: args.reserve_price,
+            duration_seconds: args.duration,
+            price_curve: args.curve.into(),
+            bid_limits: BidLimits {
+                min_bid_tokens: args.min_bid,
+                max_tokens_per_bidder: args.max_per_bidder.unwrap_or(0),
+                max_share_bps: args.max_share_bps.unwrap_or(0),
+            },
+            extension: ExtensionPolicy {
+                extension_window_seconds: args.extension_window,
+                extension_seconds: args.extension,
+                max_extension_seconds: args.max_extension.unwrap_or(args.extension),
+            },
+            min_raise: args.min_raise,
+        }
+    }
+}
+
+impl From<&AuctionArgs> for CreateAuctionArgs {
+    fn from(args: &AuctionArgs) -> Self {
+        let template = SeriesTemplate::from(&args.template);
+
+        CreateAuctionArgs {
+            batch_number: args.batch,
+            total_tokens: args.tokens,
+            start_price: template.start_price,
+            reserve_price: template.reserve_price,
+            start_time: args.start_time,
+            duration_seconds: template.duration_seconds,
+            price_curve: template.price_curve,
+            bid_limits: template.bid_limits,
+            extension: template.extension,
+            min_raise: template.min_raise,
+        }
+    }
+}
+
+pub fn run(ctx: &Context, command: Command) -> Result<()> {
+    match command {
+        Command::Create(args) => {
+            let config: ProgramConfig = ctx.fetch(&pda::config().0)?;
+            let signature = ctx.send(
+                &[builders::create_auction(
+                    ctx.signer(),
+                    config.usdc_mint,
+                    args.treasury.unwrap_or(config.treasury),
+                    CreateAuctionArgs::from(&args.auction),
+                )],
+                &[],
+            )?;
+
+            print_signature(ctx, signature, &pda::auction(args.auction.batch).0);
+        }
+        Command::Update(args) => {
+            let signature = ctx.send(
+                &[builders::update_auction(
+                    ctx.signer(),
+                    CreateAuctionArgs::from(&*args),
+                )],
+                &[],
+            )?;
+
+            print_signature(ctx, signature, &pda::auction(args.batch).0);
+        }
+        Command::Show(batch) => {
+            let address = batch.address();
+            let auction: Auction = ctx.fetch(&address)?;
+
+            ctx.print(&auction_json(&address, &auction));
+        }
+        Command::Finalize(batch) => {
+            let address = batch.address();
+            let auction: Auction = ctx.fetch(&address)?;
+            let instruction = match auction.kind {
+                AuctionKind::Dutch => builders::finalize_auction(ctx.signer(), address),
+                AuctionKind::SealedBid { .. } => {
+                    builders::finalize_sealed_auction(ctx.signer(), address)
+                }
+            };
+            let signature = ctx.send(&[instruction], &[])?;
+            print_signature(ctx, signature, &address);
+        }
+        Command::Cancel(batch) => {
+            let address = batch.address();
+            let signature = ctx.send(&[builders::cancel_auction(ctx.signer(), address)], &[])?;
+            print_signature(ctx, signature, &address);
+        }
+        Command::Refund(batch) => {
+            let address = batch.address();
+            let auction: Auction = ctx.fetch(&address)?;
+
+            let mut rows = Vec::new();
+            for (bid_address, bid) in
+                ctx.fetch_all::<Bid>(&carbon_client::dutch_auction::ID, Some(&address))?
+            {
+                if bid.status != BidStatus::Pending {
+                    continue;
+                }
+
+                let bidder_usdc = get_associated_token_address(&bid.bidder, &auction.usdc_mint);
+                let signature = ctx.send(
+                    &[builders::refund_bid(address, bid_address, bidder_usdc)],
+                    &[],
+                )?;
+                rows.push(json!({
+                    "bid": bid_address.to_string(),
+                    "bidder": bid.bidder.to_string(),
+                    "refund": bid.total_cost,
+                    "signature": signature.to_string(),
+                }));
+            }
+            ctx.print(&Value::Array(rows));
+        }
+        Command::Bids(batch) => {
+            let auction = batch.address();
+            let mut bids =
+                ctx.fetch_all::<Bid>(&carbon_client::dutch_auction::ID, Some(&auction))?;
+            bids.sort_by_key(|(_, bid)| bid.timestamp);
+
+            let rows: Vec<Value> = bids
+                .iter()
+                .map(|(address, bid)| bid_json(address, bid))
+                .collect();
+            ctx.print(&Value::Array(rows));
+        }
+        Command::Orders(batch) => {
+            let auction = batch.address();
+            let orders =
+                ctx.fetch_all::<LimitOrder>(&carbon_client::dutch_auction::ID, Some(&auction))?;
+
+            let rows: Vec<Value> = orders
+                .iter()
+                .map(|(address, order)| limit_order_json(address, order))
+                .collect();
+            ctx.print(&Value::Array(rows));
+        }
+        Command::Crank(batch) => {
+            let address = batch.address();
+            let auction: Auction = ctx.fetch(&address)?;
+
+            // Bid PDAs are seeded with the cluster time the fill lands at
+            let now = ctx.rpc.get_block_time(ctx.rpc.get_slot()?)?;
+            let price = auction_pricing::current_price(
+                &Curve::from(auction.price_curve),
+                auction.start_price,
+                auction.reserve_price,
+                auction.start_time,
+                auction.curve_end_time,
+                now,
+            )
+            .ok_or_else(|| anyhow!("price overflow"))?;
+
+            let mut rows = Vec::new();
+            for (order_address, order) in
+                ctx.fetch_all::<LimitOrder>(&carbon_client::dutch_auction::ID, Some(&address))?
+            {
+                // A scheduled auction opens on the first fill after start_time
+                if order.status != LimitOrderStatus::Open
+                    || order.limit_price < price
+                    || now < auction.start_time
+                {
+                    continue;
+                }
+                // Fills for owners out of standing are refused; the owner can close the order
+                let owner = ctx.fetch::<IndustryAccount>(&pda::industry(&order.bidder).0);
+                if !owner
+                    .is_ok_and(|owner| owner.verification_status == VerificationStatus::Verified)
+                {
+                    continue;
+                }
+
+                let signature = ctx.send(
+                    &[builders::fill_limit_order(
+                        ctx.signer(),
+                        address,
+                        order.bidder,
+                        now,
+                    )],
+                    &[],
+                )?;
+                rows.push(json!({
+                    "limit_order": order_address.to_string(),
+                    "bidder": order.bidder.to_string(),
+                    "price": price,
+                    "signature": signature.to_string(),
+                }));
+            }
+            ctx.print(&Value::Array(rows));
+        }
+    }
+    Ok(())
+}
+
+fn print_signature(ctx: &Context, signature: solana_sdk::signature::Signature, auction: &Pubkey) {
+    ctx.print(&json!({
+        "signature": signature.to_string(),
+        "auction": auction.to_string(),
+    }));
+}
+
+fn auction_json(address: &Pubkey, auction: &Auction) -> Value {
+    json!({
+        "address": address.to_string(),
+        "batch_number": auction.batch_number,
+        "series": (auction.series != Pubkey::default()).then(|| auction.series.to_string()),
+        "status": format!("{:?}", auction.status),
+        "kind": match auction.kind {
+            AuctionKind::Dutch => "dutch",
+            AuctionKind::SealedBid { .. } => "sealed-bid",
+        },
+        "total_tokens": auction.total_tokens,
+        "tokens_remaining": auction.tokens_remaining,
+        "start_price": auction.start_price,
+        "reserve_price": auction.reserve_price,
+        "price_curve": Curve::from(auction.price_curve).to_string(),
+        "min_bid_tokens": auction.bid_limits.min_bid_tokens,
+        "max_tokens_per_bidder": auction.bid_limits.max_tokens_per_bidder,
+        "max_share_bps": auction.bid_limits.max_share_bps,
+        "current_price": auction.current_price,
+        "clearing_price": auction.clearing_price,
+        "start_time": auction.start_time,
+        "end_time": auction.end_time,
+        "curve_end_time": auction.curve_end_time,
+        "extension_window_seconds": auction.extension.extension_window_seconds,
+        "extension_seconds": auction.extension.extension_seconds,
+        "max_extension_seconds": auction.extension.max_extension_seconds,
+        "reveal_end": match auction.kind {
+            AuctionKind::Dutch => None,
+            AuctionKind::SealedBid { reveal_end, .. } => Some(reveal_end),
+        },
+        "participants": auction.participant_count,
+        "total_raised": auction.total_raised,
+        "min_raise": auction.min_raise,
+        "proceeds_withdrawn": auction.proceeds_withdrawn,
+        "treasury": auction.treasury.to_string(),
+    })
+}
+
+fn bid_json(address: &Pubkey, bid: &Bid) -> Value {
+    json!({
+        "address": address.to_string(),
+        "bidder": bid.bidder.to_string(),
+        "token_amount": bid.token_amount,
+        "price_per_token": bid.price_per_token,
+        "total_cost": bid.total_cost,
+        "timestamp": bid.timestamp,
+        "status": format!("{:?}", bid.status),
+    })
+}
+
+fn limit_order_json(address: &Pubkey, order: &LimitOrder) -> Value {
+    json!({
+        "address": address.to_string(),
+        "bidder": order.bidder.to_string(),
+        "token_amount": order.token_amount,
+        "limit_price": order.limit_price,
+        "deposit": order.deposit,
+        "status": format!("{:?}", order.status),
+        "bid": (order.status == LimitOrderStatus::Filled).then(|| order.bid.to_string()),
+        "created_at": order.created_at,
+    })
+}
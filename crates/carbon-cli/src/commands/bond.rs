@@ -0,0 +1,57 @@
+use anyhow::Result;
+use carbon_client::accounts::{IndustryAccount, ProgramConfig};
+use carbon_client::{credits, get_associated_token_address, pda};
+use clap::Subcommand;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::context::Context;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Add USDC to the signer's bond vault
+    Deposit {
+        /// Amount (USDC base units)
+        amount: u64,
+
+        /// USDC token account to pay from [default: signer's USDC ATA]
+        #[arg(long)]
+        usdc_account: Option<Pubkey>,
+    },
+
+    /// Withdraw USDC from the signer's bond vault (compliant industries only)
+    Withdraw {
+        /// Amount (USDC base units)
+        amount: u64,
+
+        /// USDC token account to receive the bond [default: signer's USDC ATA]
+        #[arg(long)]
+        usdc_account: Option<Pubkey>,
+    },
+}
+
+pub fn run(ctx: &Context, command: Command) -> Result<()> {
+    let config: ProgramConfig = ctx.fetch(&pda::config().0)?;
+    let user_usdc = |account: Option<Pubkey>| {
+        account.unwrap_or_else(|| get_associated_token_address(&ctx.signer(), &config.usdc_mint))
+    };
+
+    let instruction = match command {
+        Command::Deposit {
+            amount,
+            usdc_account,
+        } => credits::deposit_bond(ctx.signer(), user_usdc(usdc_account), amount),
+        Command::Withdraw {
+            amount,
+            usdc_account,
+        } => credits::withdraw_bond(ctx.signer(), user_usdc(usdc_account), amount),
+    };
+    let signature = ctx.send(&[instruction], &[])?;
+
+    let industry: IndustryAccount = ctx.fetch(&pda::industry(&ctx.signer()).0)?;
+    ctx.print(&json!({
+        "signature": signature.to_string(),
+        "bond_amount": industry.bond_amount,
+    }));
+    Ok(())
+}
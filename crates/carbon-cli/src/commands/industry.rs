@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use carbon_client::accounts::{IndustryAccount, ProgramConfig};
+use carbon_client::{credits, get_associated_token_address, pda};
+use clap::Subcommand;
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Signer};
+
+use crate::context::Context;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Register the signer as an industry and lock its initial bond
+    Register {
+        /// Company name (max 100 bytes)
+        #[arg(long)]
+        name: String,
+
+        /// Initial bond (USDC base units)
+        #[arg(long)]
+        bond: u64,
+
+        /// USDC token account to pay the bond from [default: signer's USDC ATA]
+        #[arg(long)]
+        usdc_account: Option<Pubkey>,
+    },
+
+    /// Verify an industry and mint its credential NFT (admin or verifier)
+    Verify {
+        /// Industry authority keypair; it co-signs to delegate the credential for revocation
+        #[arg(long)]
+        industry_keypair: PathBuf,
+
+        /// Off-chain credential metadata JSON
+        #[arg(long)]
+        uri: String,
+    },
+
+    /// Show one industry
+    Show {
+        /// Industry authority wallet [default: signer]
+        authority: Option<Pubkey>,
+    },
+
+    /// List all registered industries
+    List,
+}
+
+pub fn run(ctx: &Context, command: Command) -> Result<()> {
+    match command {
+        Command::Register {
+            name,
+            bond,
+            usdc_account,
+        } => {
+            let config: ProgramConfig = ctx.fetch(&pda::config().0)?;
+            let user_usdc = usdc_account
+                .unwrap_or_else(|| get_associated_token_address(&ctx.signer(), &config.usdc_mint));
+
+            let signature = ctx.send(
+                &[credits::register_industry(
+                    ctx.signer(),
+                    config.usdc_mint,
+                    user_usdc,
+                    name,
+                    bond,
+                )],
+                &[],
+            )?;
+
+            ctx.print(&json!({
+                "signature": signature.to_string(),
+                "industry": pda::industry(&ctx.signer()).0.to_string(),
+            }));
+        }
+        Command::Verify {
+            industry_keypair,
+            uri,
+        } => {
+            let industry_signer = read_keypair_file(&industry_keypair).map_err(|err| {
+                anyhow!(
+                    "failed to read keypair {}: {}",
+                    industry_keypair.display(),
+                    err
+                )
+            })?;
+            let authority = industry_signer.pubkey();
+            let industry: IndustryAccount = ctx.fetch(&pda::industry(&authority).0)?;
+            let signature = ctx.send(
+                &[credits::verify_industry(
+                    ctx.signer(),
+                    authority,
+                    industry.credential_count,
+                    uri,
+                )],
+                &[&industry_signer],
+            )?;
+
+            let industry_key = pda::industry(&authority).0;
+            ctx.print(&json!({
+                "signature": signature.to_string(),
+                "industry": industry_key.to_string(),
+                "credential_mint": pda::credential_mint(&industry_key, industry.credential_count)
+                    .0
+                    .to_string(),
+            }));
+        }
+        Command::Show { authority } => {
+            let address = pda::industry(&authority.unwrap_or_else(|| ctx.signer())).0;
+            let industry: IndustryAccount = ctx.fetch(&address)?;
+
+            ctx.print(&industry_json(&address, &industry));
+        }
+        Command::List => {
+            let industries: Vec<Value> = ctx
+                .fetch_all::<IndustryAccount>(&carbon_client::carbon_credits::ID, None)?
+                .iter()
+                .map(|(address, industry)| industry_json(address, industry))
+                .collect();
+
+            ctx.print(&Value::Array(industries));
+        }
+    }
+    Ok(())
+}
+
+pub fn industry_json(address: &Pubkey, industry: &IndustryAccount) -> Value {
+    json!({
+        "address": address.to_string(),
+        "authority": industry.authority.to_string(),
+        "company_name": industry.company_name,
+        "verification": format!("{:?}", industry.verification_status),
+        "compliance": format!("{:?}", industry.compliance_status),
+        "bond_amount": industry.bond_amount,
+        "cct_balance": industry.cct_balance,
+        "total_purchased": industry.total_purchased,
+        "total_burned": industry.total_burned,
+        "total_slashed": industry.total_slashed,
+        "credential_mint": industry.credential_mint.to_string(),
+    })
+}
@@ -0,0 +1,51 @@
+pub mod auction;
+pub mod bond;
+pub mod industry;
+pub mod report;
+pub mod series;
+
+use anyhow::Result;
+use carbon_client::{credits, pda};
+use clap::Args;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::context::Context;
+
+#[derive(Args)]
+pub struct InitMintArgs {
+    /// USDC mint used for bonds, purchases and auction bids
+    #[arg(long)]
+    pub usdc_mint: Pubkey,
+
+    /// Minimum bond to register an industry (USDC base units)
+    #[arg(long)]
+    pub min_bond: u64,
+
+    /// USDC (base units) charged per whole CCT by `purchase_cct`
+    #[arg(long)]
+    pub cct_price: u64,
+}
+
+pub fn init_mint(ctx: &Context, args: InitMintArgs) -> Result<()> {
+    let cct_mint = Keypair::new();
+    let signature = ctx.send(
+        &[credits::initialize_mint(
+            ctx.signer(),
+            cct_mint.pubkey(),
+            args.usdc_mint,
+            args.min_bond,
+            args.cct_price,
+        )],
+        &[&cct_mint],
+    )?;
+
+    ctx.print(&json!({
+        "signature": signature.to_string(),
+        "config": pda::config().0.to_string(),
+        "cct_mint": cct_mint.pubkey().to_string(),
+        "treasury": pda::treasury().0.to_string(),
+    }));
+    Ok(())
+}
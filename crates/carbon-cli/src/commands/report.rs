@@ -0,0 +1,80 @@
+use anyhow::{anyhow, Result};
+use carbon_client::accounts::EmissionReport;
+use carbon_client::carbon_credits::normalize_report_period;
+use carbon_client::{credits, pda};
+use clap::Subcommand;
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::context::Context;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Submit the signer's emission report for a period
+    Submit {
+        /// Reporting period: YYYY, YYYY-QN or YYYY-MM
+        #[arg(long)]
+        period: String,
+
+        /// Emissions in tonnes of CO2
+        #[arg(long)]
+        co2: u64,
+    },
+
+    /// List emission reports
+    List {
+        /// Only reports of this industry authority wallet
+        #[arg(long)]
+        industry: Option<Pubkey>,
+    },
+}
+
+pub fn run(ctx: &Context, command: Command) -> Result<()> {
+    match command {
+        Command::Submit { period, co2 } => {
+            let period = normalize_report_period(&period)
+                .ok_or_else(|| anyhow!("invalid report period {:?}", period))?;
+            let industry = pda::industry(&ctx.signer()).0;
+            let report = pda::emission_report(&industry, &period).0;
+
+            let signature = ctx.send(
+                &[credits::submit_emission_report(ctx.signer(), co2, period)],
+                &[],
+            )?;
+
+            ctx.print(&json!({
+                "signature": signature.to_string(),
+                "report": report.to_string(),
+            }));
+        }
+        Command::List { industry } => {
+            let industry = industry.map(|authority| pda::industry(&authority).0);
+            let mut reports = ctx.fetch_all::<EmissionReport>(
+                &carbon_client::carbon_credits::ID,
+                industry.as_ref(),
+            )?;
+            reports.sort_by(|(_, a), (_, b)| a.report_period.cmp(&b.report_period));
+
+            let rows: Vec<Value> = reports
+                .iter()
+                .map(|(address, report)| report_json(address, report))
+                .collect();
+            ctx.print(&Value::Array(rows));
+        }
+    }
+    Ok(())
+}
+
+fn report_json(address: &Pubkey, report: &EmissionReport) -> Value {
+    json!({
+        "address": address.to_string(),
+        "industry": report.industry.to_string(),
+        "period": report.report_period,
+        "version": report.version,
+        "co2_emitted": report.co2_emitted,
+        "reported_co2": report.reported_co2,
+        "verified": report.verified,
+        "verified_by": report.verified.then(|| report.verified_by.to_string()),
+        "submitted_at": report.submitted_at,
+    })
+}
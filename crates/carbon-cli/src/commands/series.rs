@@ -0,0 +1,141 @@
+use anyhow::Result;
+use auction_pricing::Curve;
+use carbon_client::accounts::{AuctionSeries, ProgramConfig};
+use carbon_client::auction::{self as builders, CreateSeriesArgs};
+use carbon_client::dutch_auction::SeriesTemplate;
+use carbon_client::pda;
+use clap::{Args, Subcommand};
+use serde_json::{json, Value};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::commands::auction::TemplateArgs;
+use crate::context::Context;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Create a series of recurring batches (carbon-credits admin)
+    Create(Box<CreateArgs>),
+
+    /// Show a series and its next batch
+    Show { series: u32 },
+
+    /// Launch the next batch once the previous one is settled
+    Launch {
+        series: u32,
+
+        /// Opening time as a unix timestamp; a future time announces the batch as scheduled
+        /// [default: now]
+        #[arg(long, default_value_t = 0)]
+        start_time: i64,
+    },
+}
+
+#[derive(Args)]
+pub struct CreateArgs {
+    #[arg(long)]
+    series: u32,
+
+    /// Batch number of the first batch; later batches count up from it
+    #[arg(long)]
+    first_batch: u32,
+
+    /// CCT (base units) the series may sell in total
+    #[arg(long)]
+    budget: u64,
+
+    /// CCT (base units) offered per batch
+    #[arg(long)]
+    tokens_per_batch: u64,
+
+    /// Open each batch this many basis points above the previous clearing price
+    /// [default: always open at --start-price]
+    #[arg(long)]
+    adaptive_start_bps: Option<u16>,
+
+    #[command(flatten)]
+    template: TemplateArgs,
+
+    /// USDC account that receives proceeds [default: carbon-credits treasury]
+    #[arg(long)]
+    treasury: Option<Pubkey>,
+}
+
+pub fn run(ctx: &Context, command: Command) -> Result<()> {
+    match command {
+        Command::Create(args) => {
+            let config: ProgramConfig = ctx.fetch(&pda::config().0)?;
+            let signature = ctx.send(
+                &[builders::create_series(
+                    ctx.signer(),
+                    config.usdc_mint,
+                    args.treasury.unwrap_or(config.treasury),
+                    CreateSeriesArgs {
+                        series_id: args.series,
+                        first_batch_number: args.first_batch,
+                        supply_budget: args.budget,
+                        tokens_per_batch: args.tokens_per_batch,
+                        template: SeriesTemplate::from(&args.template),
+                        adaptive_start_bps: args.adaptive_start_bps,
+                    },
+                )],
+                &[],
+            )?;
+
+            ctx.print(&json!({
+                "signature": signature.to_string(),
+                "series": pda::auction_series(args.series).0.to_string(),
+            }));
+        }
+        Command::Show { series } => {
+            let address = pda::auction_series(series).0;
+            let state: AuctionSeries = ctx.fetch(&address)?;
+
+            ctx.print(&series_json(&address, &state));
+        }
+        Command::Launch { series, start_time } => {
+            let address = pda::auction_series(series).0;
+            let state: AuctionSeries = ctx.fetch(&address)?;
+            let signature = ctx.send(
+                &[builders::launch_next_batch(
+                    ctx.signer(),
+                    &state,
+                    start_time,
+                )],
+                &[],
+            )?;
+
+            ctx.print(&json!({
+                "signature": signature.to_string(),
+                "batch_number": state.next_batch_number,
+                "auction": pda::series_auction(&address, state.next_batch_number).0.to_string(),
+            }));
+        }
+    }
+    Ok(())
+}
+
+fn series_json(address: &Pubkey, series: &AuctionSeries) -> Value {
+    let template = &series.template;
+    json!({
+        "address": address.to_string(),
+        "series_id": series.series_id,
+        "next_batch_number": series.next_batch_number,
+        "batches_launched": series.batches_launched,
+        "supply_budget": series.supply_budget,
+        "supply_sold": series.supply_sold,
+        "tokens_per_batch": series.tokens_per_batch,
+        "start_price": template.start_price,
+        "adaptive_start_bps": series.adaptive_start_bps,
+        "reserve_price": template.reserve_price,
+        "duration_seconds": template.duration_seconds,
+        "price_curve": Curve::from(template.price_curve).to_string(),
+        "min_bid_tokens": template.bid_limits.min_bid_tokens,
+        "max_tokens_per_bidder": template.bid_limits.max_tokens_per_bidder,
+        "max_share_bps": template.bid_limits.max_share_bps,
+        "extension_window_seconds": template.extension.extension_window_seconds,
+        "extension_seconds": template.extension.extension_seconds,
+        "max_extension_seconds": template.extension.max_extension_seconds,
+        "min_raise": template.min_raise,
+        "treasury": series.treasury.to_string(),
+    })
+}
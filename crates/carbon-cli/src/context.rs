@@ -0,0 +1,125 @@
+//! RPC connection, signer and account fetching shared by all commands
+
+use std::path::PathBuf;
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{anyhow, Context as _, Result};
+use carbon_client::accounts;
+use serde_json::Value;
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+use crate::output::{self, Format};
+
+pub struct Context {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub format: Format,
+}
+
+impl Context {
+    pub fn new(url: &str, keypair: Option<PathBuf>, format: Format) -> Result<Self> {
+        let path = match keypair {
+            Some(path) => path,
+            None => default_keypair_path()?,
+        };
+        let payer = read_keypair_file(&path)
+            .map_err(|err| anyhow!("failed to read keypair {}: {}", path.display(), err))?;
+
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()),
+            payer,
+            format,
+        })
+    }
+
+    pub fn signer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    /// Sign with the payer (plus any extra signers) and wait for confirmation
+    pub fn send(
+        &self,
+        instructions: &[Instruction],
+        extra_signers: &[&Keypair],
+    ) -> Result<Signature> {
+        let mut signers: Vec<&Keypair> = vec![&self.payer];
+        signers.extend_from_slice(extra_signers);
+
+        let blockhash = self.rpc.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .context("transaction failed")
+    }
+
+    /// Fetch and decode a single program account
+    pub fn fetch<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<T> {
+        let data = self
+            .rpc
+            .get_account_data(address)
+            .with_context(|| format!("account {} not found", address))?;
+
+        accounts::decode(&data).map_err(|err| anyhow!("failed to decode {}: {}", address, err))
+    }
+
+    /// Fetch every account of type `T`, optionally matching a pubkey stored right after the
+    /// discriminator (e.g. `EmissionReport::industry`, `Bid::auction`)
+    pub fn fetch_all<T: AccountDeserialize + Discriminator>(
+        &self,
+        program_id: &Pubkey,
+        owner_field: Option<&Pubkey>,
+    ) -> Result<Vec<(Pubkey, T)>> {
+        let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            0,
+            &T::DISCRIMINATOR,
+        ))];
+        if let Some(key) = owner_field {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                8,
+                key.as_ref(),
+            )));
+        }
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        self.rpc
+            .get_program_accounts_with_config(program_id, config)?
+            .into_iter()
+            .map(|(address, account)| {
+                let decoded = accounts::decode(&account.data)
+                    .map_err(|err| anyhow!("failed to decode {}: {}", address, err))?;
+                Ok((address, decoded))
+            })
+            .collect()
+    }
+
+    pub fn print(&self, value: &Value) {
+        output::print(self.format, value);
+    }
+}
+
+fn default_keypair_path() -> Result<PathBuf> {
+    let home = std::env::var_os("HOME").context("HOME is not set; pass --keypair")?;
+    Ok(PathBuf::from(home).join(".config/solana/id.json"))
+}
@@ -0,0 +1,83 @@
+//! `carbon` - operator CLI for the carbon-credits and dutch-auction programs
+
+mod commands;
+mod context;
+mod output;
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::commands::{auction, bond, industry, report, series};
+use crate::context::Context;
+use crate::output::Format;
+
+#[derive(Parser)]
+#[command(
+    name = "carbon",
+    version,
+    about = "Administer the CarbonOnChain programs"
+)]
+struct Cli {
+    /// JSON RPC endpoint
+    #[arg(
+        short = 'u',
+        long,
+        global = true,
+        env = "CARBON_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Signer and fee payer keypair [default: ~/.config/solana/id.json]
+    #[arg(short, long, global = true, env = "CARBON_KEYPAIR")]
+    keypair: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Table)]
+    output: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the CCT mint, program config and USDC treasury
+    InitMint(commands::InitMintArgs),
+
+    /// Register, verify and inspect industries
+    #[command(subcommand)]
+    Industry(industry::Command),
+
+    /// Deposit or withdraw the signer's industry bond
+    #[command(subcommand)]
+    Bond(bond::Command),
+
+    /// Submit and list emission reports
+    #[command(subcommand)]
+    Report(report::Command),
+
+    /// Create, inspect and settle auctions
+    #[command(subcommand)]
+    Auction(auction::Command),
+
+    /// Run recurring auction batches from a shared supply budget
+    #[command(subcommand)]
+    Series(series::Command),
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let ctx = Context::new(&cli.url, cli.keypair, cli.output)?;
+
+    match cli.command {
+        Command::InitMint(args) => commands::init_mint(&ctx, args),
+        Command::Industry(command) => industry::run(&ctx, command),
+        Command::Bond(command) => bond::run(&ctx, command),
+        Command::Report(command) => report::run(&ctx, command),
+        Command::Auction(command) => auction::run(&ctx, command),
+        Command::Series(command) => series::run(&ctx, command),
+    }
+}
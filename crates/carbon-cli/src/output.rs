@@ -0,0 +1,62 @@
+//! Table and JSON rendering
+//!
+//! Every command produces a `serde_json::Value`: an object for a single record or an array
+//! of objects for a listing. JSON output prints it as-is; table output lays objects out as
+//! field/value pairs and arrays as one row per element.
+
+use clap::ValueEnum;
+use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub fn print(format: Format, value: &Value) {
+    match format {
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(value).expect("JSON values always serialize")
+        ),
+        Format::Table => println!("{}", render_table(value)),
+    }
+}
+
+fn render_table(value: &Value) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+
+    match value {
+        Value::Array(rows) => {
+            if let Some(Value::Object(first)) = rows.first() {
+                table.set_header(first.keys());
+            }
+            for row in rows {
+                if let Value::Object(fields) = row {
+                    table.add_row(fields.values().map(cell));
+                }
+            }
+        }
+        Value::Object(fields) => {
+            table.set_header(["field", "value"]);
+            for (key, value) in fields {
+                table.add_row([key.clone(), cell(value)]);
+            }
+        }
+        other => {
+            table.add_row([cell(other)]);
+        }
+    }
+
+    table
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
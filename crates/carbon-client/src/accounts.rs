@@ -0,0 +1,59 @@
+//! Account decoders
+//!
+//! Decoding checks the Anchor discriminator, so passing the wrong account type fails
+//! instead of producing garbage.
+
+use anchor_lang::{AccountDeserialize, Result};
+
+pub use carbon_credits::{
+    Auditor, ComplianceObligation, CompliancePeriod, EmissionReport, EmissionReportRevision,
+    IndustryAccount, ProgramConfig,
+};
+pub use dutch_auction::{
+    Auction, AuctionSeries, Bid, BidderPosition, LimitOrder, SealedBid, SealedBook,
+};
+
+/// Decode any program account from its raw data
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn program_config(data: &[u8]) -> Result<ProgramConfig> {
+    decode(data)
+}
+
+pub fn industry_account(data: &[u8]) -> Result<IndustryAccount> {
+    decode(data)
+}
+
+pub fn emission_report(data: &[u8]) -> Result<EmissionReport> {
+    decode(data)
+}
+
+pub fn auction(data: &[u8]) -> Result<Auction> {
+    decode(data)
+}
+
+pub fn bid(data: &[u8]) -> Result<Bid> {
+    decode(data)
+}
+
+pub fn bidder_position(data: &[u8]) -> Result<BidderPosition> {
+    decode(data)
+}
+
+pub fn limit_order(data: &[u8]) -> Result<LimitOrder> {
+    decode(data)
+}
+
+pub fn sealed_bid(data: &[u8]) -> Result<SealedBid> {
+    decode(data)
+}
+
+pub fn sealed_book(data: &[u8]) -> Result<SealedBook> {
+    decode(data)
+}
+
+pub fn auction_series(data: &[u8]) -> Result<AuctionSeries> {
+    decode(data)
+}
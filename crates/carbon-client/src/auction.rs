@@ -0,0 +1,475 @@
+//! Instruction builders for the `dutch-auction` program
+//!
+//! Auctions are created by batch number and afterwards addressed by their account, from
+//! `pda::auction` for standalone batches or `pda::series_auction` for series batches; the
+//! escrow and authority PDAs are derived from it.
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::token;
+use dutch_auction::{
+    accounts, instruction, AuctionSeries, BidLimits, ExtensionPolicy, PriceCurve, SeriesTemplate,
+};
+
+use crate::{build, pda, Instruction};
+
+/// Parameters of a new auction batch
+#[derive(Clone, Debug)]
+pub struct CreateAuctionArgs {
+    pub batch_number: u32,
+    pub total_tokens: u64,
+    pub start_price: u64,
+    pub reserve_price: u64,
+    /// Opening time; now or earlier opens immediately, later schedules the batch
+    pub start_time: i64,
+    pub duration_seconds: i64,
+    pub price_curve: PriceCurve,
+    pub bid_limits: BidLimits,
+    pub extension: ExtensionPolicy,
+    /// USDC the auction must raise or fail, 0 for no minimum
+    pub min_raise: u64,
+}
+
+pub fn create_auction(
+    authority: Pubkey,
+    usdc_mint: Pubkey,
+    treasury: Pubkey,
+    args: CreateAuctionArgs,
+) -> Instruction {
+    let auction = pda::auction(args.batch_number).0;
+
+    build(
+        dutch_auction::ID,
+        accounts::CreateAuction {
+            auction,
+            cct_config: pda::config().0,
+            usdc_mint,
+            escrow_usdc: pda::escrow(&auction).0,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            treasury,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateAuction {
+            batch_number: args.batch_number,
+            total_tokens: args.total_tokens,
+            start_price: args.start_price,
+            reserve_price: args.reserve_price,
+            start_time: args.start_time,
+            duration_seconds: args.duration_seconds,
+            price_curve: args.price_curve,
+            bid_limits: args.bid_limits,
+            extension: args.extension,
+            min_raise: args.min_raise,
+        },
+    )
+}
+
+/// Replace the parameters of a scheduled standalone auction; `args.batch_number` selects it
+pub fn update_auction(authority: Pubkey, args: CreateAuctionArgs) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::UpdateAuction {
+            auction: pda::auction(args.batch_number).0,
+            authority,
+        },
+        instruction::UpdateAuction {
+            total_tokens: args.total_tokens,
+            start_price: args.start_price,
+            reserve_price: args.reserve_price,
+            start_time: args.start_time,
+            duration_seconds: args.duration_seconds,
+            price_curve: args.price_curve,
+            bid_limits: args.bid_limits,
+            extension: args.extension,
+            min_raise: args.min_raise,
+        },
+    )
+}
+
+/// `timestamp` must match the cluster clock when the bid lands, as it seeds the bid PDA.
+/// The bid fails if the price at that time is above `max_price_per_token`.
+pub fn place_bid(
+    bidder: Pubkey,
+    auction: Pubkey,
+    bidder_usdc: Pubkey,
+    timestamp: i64,
+    token_amount: u64,
+    max_price_per_token: u64,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::PlaceBid {
+            auction,
+            bid: pda::bid(&auction, &bidder, timestamp).0,
+            bidder_usdc,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_position: pda::bidder_position(&auction, &bidder).0,
+            industry_account: pda::industry(&bidder).0,
+            bidder,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::PlaceBid {
+            token_amount,
+            max_price_per_token,
+        },
+    )
+}
+
+/// Escrows the cost of `token_amount` at `limit_price` per CCT from `bidder_usdc`
+pub fn place_limit_order(
+    bidder: Pubkey,
+    auction: Pubkey,
+    bidder_usdc: Pubkey,
+    token_amount: u64,
+    limit_price: u64,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::PlaceLimitOrder {
+            auction,
+            limit_order: pda::limit_order(&auction, &bidder).0,
+            bidder_usdc,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_position: pda::bidder_position(&auction, &bidder).0,
+            industry_account: pda::industry(&bidder).0,
+            bidder,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::PlaceLimitOrder {
+            token_amount,
+            limit_price,
+        },
+    )
+}
+
+/// Crank `bidder`'s order; `timestamp` seeds the resulting bid PDA like in `place_bid`
+pub fn fill_limit_order(
+    cranker: Pubkey,
+    auction: Pubkey,
+    bidder: Pubkey,
+    timestamp: i64,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::FillLimitOrder {
+            auction,
+            limit_order: pda::limit_order(&auction, &bidder).0,
+            bid: pda::bid(&auction, &bidder, timestamp).0,
+            bidder_position: pda::bidder_position(&auction, &bidder).0,
+            industry_account: pda::industry(&bidder).0,
+            cranker,
+            system_program: system_program::ID,
+        },
+        instruction::FillLimitOrder {},
+    )
+}
+
+pub fn close_limit_order(bidder: Pubkey, auction: Pubkey, bidder_usdc: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::CloseLimitOrder {
+            auction,
+            limit_order: pda::limit_order(&auction, &bidder).0,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_usdc,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            bidder,
+            token_program: token::ID,
+        },
+        instruction::CloseLimitOrder {},
+    )
+}
+
+pub fn finalize_auction(authority: Pubkey, auction: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::FinalizeAuction { auction, authority },
+        instruction::FinalizeAuction {},
+    )
+}
+
+/// Won CCT goes to the bidder's associated token account, created if missing
+pub fn claim_tokens(
+    bidder: Pubkey,
+    auction: Pubkey,
+    bid: Pubkey,
+    bidder_usdc: Pubkey,
+    cct_mint: Pubkey,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::ClaimTokens {
+            auction,
+            bid,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_usdc,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            cct_config: pda::config().0,
+            industry_account: pda::industry(&bidder).0,
+            cct_mint,
+            bidder_cct: get_associated_token_address(&bidder, &cct_mint),
+            mint_authority: pda::mint_authority().0,
+            issuer: pda::cct_issuer().0,
+            bidder,
+            carbon_credits_program: carbon_credits::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimTokens {},
+    )
+}
+
+pub fn withdraw_proceeds(authority: Pubkey, auction: Pubkey, treasury: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::WithdrawProceeds {
+            auction,
+            escrow_usdc: pda::escrow(&auction).0,
+            treasury,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            authority,
+            token_program: token::ID,
+        },
+        instruction::WithdrawProceeds {},
+    )
+}
+
+/// Refund `bid` in full from a cancelled or failed auction; anyone may send it
+pub fn refund_bid(auction: Pubkey, bid: Pubkey, bidder_usdc: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::RefundBid {
+            auction,
+            bid,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_usdc,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            token_program: token::ID,
+        },
+        instruction::RefundBid {},
+    )
+}
+
+/// Parameters of a new sealed-bid auction batch
+#[derive(Clone, Debug)]
+pub struct CreateSealedAuctionArgs {
+    pub batch_number: u32,
+    pub total_tokens: u64,
+    pub reserve_price: u64,
+    pub commit_seconds: i64,
+    pub reveal_seconds: i64,
+    pub forfeit_bps: u16,
+    pub bid_limits: BidLimits,
+}
+
+pub fn create_sealed_auction(
+    authority: Pubkey,
+    usdc_mint: Pubkey,
+    treasury: Pubkey,
+    args: CreateSealedAuctionArgs,
+) -> Instruction {
+    let auction = pda::auction(args.batch_number).0;
+
+    build(
+        dutch_auction::ID,
+        accounts::CreateSealedAuction {
+            auction,
+            sealed_book: pda::sealed_book(&auction).0,
+            cct_config: pda::config().0,
+            usdc_mint,
+            escrow_usdc: pda::escrow(&auction).0,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            treasury,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSealedAuction {
+            batch_number: args.batch_number,
+            total_tokens: args.total_tokens,
+            reserve_price: args.reserve_price,
+            commit_seconds: args.commit_seconds,
+            reveal_seconds: args.reveal_seconds,
+            forfeit_bps: args.forfeit_bps,
+            bid_limits: args.bid_limits,
+        },
+    )
+}
+
+/// Build `commitment` with `dutch_auction::sealed_bid_commitment` and keep the salt for the
+/// reveal
+pub fn commit_bid(
+    bidder: Pubkey,
+    auction: Pubkey,
+    bidder_usdc: Pubkey,
+    commitment: [u8; 32],
+    deposit: u64,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::CommitBid {
+            auction,
+            sealed_bid: pda::sealed_bid(&auction, &bidder).0,
+            bidder_usdc,
+            escrow_usdc: pda::escrow(&auction).0,
+            industry_account: pda::industry(&bidder).0,
+            bidder,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::CommitBid {
+            commitment,
+            deposit,
+        },
+    )
+}
+
+pub fn reveal_bid(
+    bidder: Pubkey,
+    auction: Pubkey,
+    token_amount: u64,
+    price_per_token: u64,
+    salt: [u8; 32],
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::RevealBid {
+            auction,
+            sealed_bid: pda::sealed_bid(&auction, &bidder).0,
+            sealed_book: pda::sealed_book(&auction).0,
+            bidder,
+        },
+        instruction::RevealBid {
+            token_amount,
+            price_per_token,
+            salt,
+        },
+    )
+}
+
+pub fn finalize_sealed_auction(authority: Pubkey, auction: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::FinalizeSealedAuction {
+            auction,
+            sealed_book: pda::sealed_book(&auction).0,
+            authority,
+        },
+        instruction::FinalizeSealedAuction {},
+    )
+}
+
+/// Won CCT goes to the bidder's associated token account, created if missing
+pub fn claim_sealed_bid(
+    bidder: Pubkey,
+    auction: Pubkey,
+    bidder_usdc: Pubkey,
+    treasury: Pubkey,
+    cct_mint: Pubkey,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::ClaimSealedBid {
+            auction,
+            sealed_book: pda::sealed_book(&auction).0,
+            sealed_bid: pda::sealed_bid(&auction, &bidder).0,
+            escrow_usdc: pda::escrow(&auction).0,
+            bidder_usdc,
+            treasury,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            cct_config: pda::config().0,
+            industry_account: pda::industry(&bidder).0,
+            cct_mint,
+            bidder_cct: get_associated_token_address(&bidder, &cct_mint),
+            mint_authority: pda::mint_authority().0,
+            issuer: pda::cct_issuer().0,
+            bidder,
+            carbon_credits_program: carbon_credits::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ClaimSealedBid {},
+    )
+}
+
+pub fn cancel_auction(authority: Pubkey, auction: Pubkey) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::CancelAuction { auction, authority },
+        instruction::CancelAuction {},
+    )
+}
+
+/// Parameters of a new auction series
+#[derive(Clone, Debug)]
+pub struct CreateSeriesArgs {
+    pub series_id: u32,
+    pub first_batch_number: u32,
+    pub supply_budget: u64,
+    pub tokens_per_batch: u64,
+    pub template: SeriesTemplate,
+    pub adaptive_start_bps: Option<u16>,
+}
+
+pub fn create_series(
+    authority: Pubkey,
+    usdc_mint: Pubkey,
+    treasury: Pubkey,
+    args: CreateSeriesArgs,
+) -> Instruction {
+    build(
+        dutch_auction::ID,
+        accounts::CreateSeries {
+            series: pda::auction_series(args.series_id).0,
+            cct_config: pda::config().0,
+            usdc_mint,
+            treasury,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSeries {
+            series_id: args.series_id,
+            first_batch_number: args.first_batch_number,
+            supply_budget: args.supply_budget,
+            tokens_per_batch: args.tokens_per_batch,
+            template: args.template,
+            adaptive_start_bps: args.adaptive_start_bps,
+        },
+    )
+}
+
+/// Launch the batch after `series`' latest one, as fetched from `pda::auction_series`
+pub fn launch_next_batch(
+    authority: Pubkey,
+    series: &AuctionSeries,
+    start_time: i64,
+) -> Instruction {
+    let series_address = pda::auction_series(series.series_id).0;
+    let auction = pda::series_auction(&series_address, series.next_batch_number).0;
+    let previous_auction = (series.batches_launched > 0)
+        .then(|| pda::series_auction(&series_address, series.next_batch_number.wrapping_sub(1)).0);
+
+    build(
+        dutch_auction::ID,
+        accounts::LaunchNextBatch {
+            series: series_address,
+            previous_auction,
+            auction,
+            cct_config: pda::config().0,
+            usdc_mint: series.usdc_mint,
+            escrow_usdc: pda::escrow(&auction).0,
+            escrow_authority: pda::escrow_authority(&auction).0,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::LaunchNextBatch { start_time },
+    )
+}
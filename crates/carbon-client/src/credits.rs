@@ -0,0 +1,563 @@
+//! Instruction builders for the `carbon-credits` program
+//!
+//! Builders take the signer and the handful of keys that cannot be derived (mints, token
+//! accounts, the industry authority) and fill in every PDA and program account.
+
+use anchor_lang::solana_program::{pubkey::Pubkey, sysvar};
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, get_associated_token_address};
+use anchor_spl::{metadata, token};
+use carbon_credits::{accounts, instruction};
+
+use crate::{build, pda, Instruction};
+
+pub fn initialize_mint(
+    authority: Pubkey,
+    cct_mint: Pubkey,
+    usdc_mint: Pubkey,
+    min_bond: u64,
+    cct_price: u64,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::InitializeMint {
+            cct_mint,
+            mint_authority: pda::mint_authority().0,
+            config: pda::config().0,
+            usdc_mint,
+            treasury: pda::treasury().0,
+            treasury_authority: pda::treasury_authority().0,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::InitializeMint {
+            min_bond,
+            cct_price,
+        },
+    )
+}
+
+fn update_config(admin: Pubkey) -> accounts::UpdateConfig {
+    accounts::UpdateConfig {
+        config: pda::config().0,
+        admin,
+    }
+}
+
+pub fn update_cct_price(admin: Pubkey, cct_price: u64) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::UpdateCctPrice { cct_price },
+    )
+}
+
+pub fn update_min_bond(admin: Pubkey, min_bond: u64) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::UpdateMinBond { min_bond },
+    )
+}
+
+pub fn update_slash_policy(
+    admin: Pubkey,
+    penalty_treasury: Pubkey,
+    slash_bps: u16,
+    slash_grace_period: i64,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::UpdateSlashPolicy {
+            config: pda::config().0,
+            penalty_treasury,
+            admin,
+        },
+        instruction::UpdateSlashPolicy {
+            slash_bps,
+            slash_grace_period,
+        },
+    )
+}
+
+pub fn add_verifier(admin: Pubkey, verifier: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::AddVerifier { verifier },
+    )
+}
+
+pub fn remove_verifier(admin: Pubkey, verifier: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::RemoveVerifier { verifier },
+    )
+}
+
+/// Usually called with `pda::cct_issuer()`
+pub fn set_auction_issuer(admin: Pubkey, issuer: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::SetAuctionIssuer { issuer },
+    )
+}
+
+pub fn propose_admin(admin: Pubkey, new_admin: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        update_config(admin),
+        instruction::ProposeAdmin { new_admin },
+    )
+}
+
+pub fn accept_admin(new_admin: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::AcceptAdmin {
+            config: pda::config().0,
+            new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn register_industry(
+    authority: Pubkey,
+    usdc_mint: Pubkey,
+    user_usdc: Pubkey,
+    company_name: String,
+    bond_amount: u64,
+) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::RegisterIndustry {
+            config: pda::config().0,
+            industry_account,
+            usdc_mint,
+            bond_vault: pda::bond_vault(&industry_account).0,
+            vault_authority: pda::bond_vault_authority().0,
+            user_usdc,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterIndustry {
+            company_name,
+            bond_amount,
+        },
+    )
+}
+
+/// `credential_count` is read from the industry account before verification
+/// and `industry_authority` must co-sign, delegating the credential for revocation
+pub fn verify_industry(
+    verifier: Pubkey,
+    industry_authority: Pubkey,
+    credential_count: u32,
+    metadata_uri: String,
+) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+    let credential_mint = pda::credential_mint(&industry_account, credential_count).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::VerifyIndustry {
+            config: pda::config().0,
+            industry_account,
+            industry_authority,
+            credential_mint,
+            credential_account: get_associated_token_address(&industry_authority, &credential_mint),
+            credential_authority: pda::credential_authority().0,
+            metadata: pda::metadata(&credential_mint).0,
+            master_edition: pda::master_edition(&credential_mint).0,
+            verifier,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            token_metadata_program: metadata::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::VerifyIndustry { metadata_uri },
+    )
+}
+
+fn set_verification_status(
+    admin: Pubkey,
+    industry_authority: Pubkey,
+    credential_mint: Pubkey,
+) -> accounts::SetVerificationStatus {
+    accounts::SetVerificationStatus {
+        config: pda::config().0,
+        industry_account: pda::industry(&industry_authority).0,
+        metadata: pda::metadata(&credential_mint).0,
+        credential_authority: pda::credential_authority().0,
+        admin,
+        token_metadata_program: metadata::ID,
+    }
+}
+
+pub fn suspend_industry(
+    admin: Pubkey,
+    industry_authority: Pubkey,
+    credential_mint: Pubkey,
+    reason_code: u16,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        set_verification_status(admin, industry_authority, credential_mint),
+        instruction::SuspendIndustry { reason_code },
+    )
+}
+
+pub fn reinstate_industry(
+    admin: Pubkey,
+    industry_authority: Pubkey,
+    credential_mint: Pubkey,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        set_verification_status(admin, industry_authority, credential_mint),
+        instruction::ReinstateIndustry {},
+    )
+}
+
+pub fn revoke_industry(
+    admin: Pubkey,
+    industry_authority: Pubkey,
+    credential_mint: Pubkey,
+    reason_code: u16,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::RevokeIndustry {
+            config: pda::config().0,
+            industry_account: pda::industry(&industry_authority).0,
+            credential_mint,
+            credential_account: get_associated_token_address(&industry_authority, &credential_mint),
+            metadata: pda::metadata(&credential_mint).0,
+            master_edition: pda::master_edition(&credential_mint).0,
+            credential_authority: pda::credential_authority().0,
+            admin,
+            token_program: token::ID,
+            token_metadata_program: metadata::ID,
+        },
+        instruction::RevokeIndustry { reason_code },
+    )
+}
+
+pub fn deposit_bond(authority: Pubkey, user_usdc: Pubkey, amount: u64) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::DepositBond {
+            config: pda::config().0,
+            industry_account,
+            user_usdc,
+            bond_vault: pda::bond_vault(&industry_account).0,
+            authority,
+            token_program: token::ID,
+        },
+        instruction::DepositBond { amount },
+    )
+}
+
+/// CCT is minted to the authority's associated token account, created if missing
+pub fn purchase_cct(
+    authority: Pubkey,
+    cct_mint: Pubkey,
+    user_usdc: Pubkey,
+    amount: u64,
+    max_price_per_token: u64,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::PurchaseCCT {
+            config: pda::config().0,
+            industry_account: pda::industry(&authority).0,
+            cct_mint,
+            user_usdc,
+            treasury: pda::treasury().0,
+            industry_cct_account: get_associated_token_address(&authority, &cct_mint),
+            mint_authority: pda::mint_authority().0,
+            authority,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::PurchaseCct {
+            amount,
+            max_price_per_token,
+        },
+    )
+}
+
+/// Only the registered auction issuer PDA can sign this; see `auction::claim_tokens`
+pub fn issue_auction_cct(
+    issuer: Pubkey,
+    industry_authority: Pubkey,
+    cct_mint: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::IssueAuctionCCT {
+            config: pda::config().0,
+            industry_account: pda::industry(&industry_authority).0,
+            cct_mint,
+            industry_cct_account: get_associated_token_address(&industry_authority, &cct_mint),
+            mint_authority: pda::mint_authority().0,
+            issuer,
+            token_program: token::ID,
+        },
+        instruction::IssueAuctionCct { amount },
+    )
+}
+
+pub fn burn_cct_for_compliance(
+    authority: Pubkey,
+    cct_mint: Pubkey,
+    period_id: u32,
+    amount: u64,
+) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+    let compliance_period = pda::compliance_period(period_id).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::BurnCCT {
+            config: pda::config().0,
+            industry_account,
+            compliance_period,
+            obligation: pda::obligation(&compliance_period, &industry_account).0,
+            cct_mint,
+            industry_cct_account: get_associated_token_address(&authority, &cct_mint),
+            authority,
+            token_program: token::ID,
+        },
+        instruction::BurnCctForCompliance { amount },
+    )
+}
+
+pub fn create_compliance_period(
+    admin: Pubkey,
+    period_id: u32,
+    label: String,
+    start_time: i64,
+    deadline: i64,
+    warning_bps: u16,
+) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::CreateCompliancePeriod {
+            config: pda::config().0,
+            compliance_period: pda::compliance_period(period_id).0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::CreateCompliancePeriod {
+            period_id,
+            label,
+            start_time,
+            deadline,
+            warning_bps,
+        },
+    )
+}
+
+pub fn open_compliance_obligation(
+    payer: Pubkey,
+    industry_authority: Pubkey,
+    period_id: u32,
+) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+    let compliance_period = pda::compliance_period(period_id).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::OpenComplianceObligation {
+            compliance_period,
+            industry_account,
+            obligation: pda::obligation(&compliance_period, &industry_account).0,
+            payer,
+            system_program: system_program::ID,
+        },
+        instruction::OpenComplianceObligation {},
+    )
+}
+
+pub fn record_emission_report(
+    industry_authority: Pubkey,
+    period_id: u32,
+    report_period: &str,
+) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+    let compliance_period = pda::compliance_period(period_id).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::RecordEmissionReport {
+            compliance_period,
+            obligation: pda::obligation(&compliance_period, &industry_account).0,
+            emission_report: pda::emission_report(&industry_account, report_period).0,
+        },
+        instruction::RecordEmissionReport {},
+    )
+}
+
+pub fn evaluate_compliance(industry_authority: Pubkey, period_id: u32) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+    let compliance_period = pda::compliance_period(period_id).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::EvaluateCompliance {
+            compliance_period,
+            obligation: pda::obligation(&compliance_period, &industry_account).0,
+            industry_account,
+        },
+        instruction::EvaluateCompliance {},
+    )
+}
+
+/// `report_period` must already be canonical, e.g. `"2025-Q3"`
+pub fn submit_emission_report(
+    authority: Pubkey,
+    co2_emitted: u64,
+    report_period: String,
+) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::SubmitEmissionReport {
+            emission_report: pda::emission_report(&industry_account, &report_period).0,
+            industry_account,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::SubmitEmissionReport {
+            co2_emitted,
+            report_period,
+        },
+    )
+}
+
+/// `current_version` is the report's version before the amendment
+pub fn amend_emission_report(
+    authority: Pubkey,
+    report_period: &str,
+    current_version: u32,
+    co2_emitted: u64,
+) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+    let emission_report = pda::emission_report(&industry_account, report_period).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::AmendEmissionReport {
+            emission_report,
+            revision: pda::emission_report_revision(&emission_report, current_version).0,
+            industry_account,
+            authority,
+            system_program: system_program::ID,
+        },
+        instruction::AmendEmissionReport { co2_emitted },
+    )
+}
+
+pub fn register_auditor(admin: Pubkey, authority: Pubkey, name: String) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::RegisterAuditor {
+            config: pda::config().0,
+            auditor: pda::auditor(&authority).0,
+            admin,
+            system_program: system_program::ID,
+        },
+        instruction::RegisterAuditor { authority, name },
+    )
+}
+
+pub fn revoke_auditor(admin: Pubkey, auditor_authority: Pubkey) -> Instruction {
+    build(
+        carbon_credits::ID,
+        accounts::RevokeAuditor {
+            config: pda::config().0,
+            auditor: pda::auditor(&auditor_authority).0,
+            admin,
+        },
+        instruction::RevokeAuditor {},
+    )
+}
+
+pub fn verify_emission_report(
+    auditor_authority: Pubkey,
+    industry_authority: Pubkey,
+    report_period: &str,
+    adjusted_co2: Option<u64>,
+    evidence_hash: [u8; 32],
+) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::VerifyEmissionReport {
+            auditor: pda::auditor(&auditor_authority).0,
+            emission_report: pda::emission_report(&industry_account, report_period).0,
+            industry_account,
+            auditor_authority,
+        },
+        instruction::VerifyEmissionReport {
+            adjusted_co2,
+            evidence_hash,
+        },
+    )
+}
+
+pub fn withdraw_bond(authority: Pubkey, user_usdc: Pubkey, amount: u64) -> Instruction {
+    let industry_account = pda::industry(&authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::WithdrawBond {
+            config: pda::config().0,
+            industry_account,
+            bond_vault: pda::bond_vault(&industry_account).0,
+            user_usdc,
+            vault_authority: pda::bond_vault_authority().0,
+            authority,
+            token_program: token::ID,
+        },
+        instruction::WithdrawBond { amount },
+    )
+}
+
+pub fn slash_bond(
+    admin: Pubkey,
+    industry_authority: Pubkey,
+    penalty_treasury: Pubkey,
+) -> Instruction {
+    let industry_account = pda::industry(&industry_authority).0;
+
+    build(
+        carbon_credits::ID,
+        accounts::SlashBond {
+            config: pda::config().0,
+            industry_account,
+            bond_vault: pda::bond_vault(&industry_account).0,
+            vault_authority: pda::bond_vault_authority().0,
+            penalty_treasury,
+            admin,
+            token_program: token::ID,
+        },
+        instruction::SlashBond {},
+    )
+}
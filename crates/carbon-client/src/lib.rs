@@ -0,0 +1,32 @@
+//! Off-chain client for the CarbonOnChain programs
+//!
+//! Builds `Instruction`s for every `carbon-credits` and `dutch-auction` instruction, derives
+//! their PDAs and decodes their accounts. The programs are linked with `no-entrypoint`, so
+//! this crate can be used from any backend service.
+
+pub mod accounts;
+pub mod auction;
+pub mod credits;
+pub mod pda;
+
+pub use carbon_credits;
+pub use dutch_auction;
+
+pub use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+pub use anchor_lang::solana_program::pubkey::Pubkey;
+pub use anchor_spl::associated_token::get_associated_token_address;
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+/// Assemble an instruction from an Anchor accounts struct and instruction args
+fn build(
+    program_id: Pubkey,
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
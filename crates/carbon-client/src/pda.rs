@@ -0,0 +1,199 @@
+//! Program-derived addresses used by both programs
+//!
+//! Every helper mirrors the `seeds` of the matching account constraint and returns the
+//! address together with its canonical bump.
+
+use anchor_lang::solana_program::pubkey::Pubkey;
+
+// ----------------------------------------------------------------------------
+// carbon-credits
+// ----------------------------------------------------------------------------
+
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &carbon_credits::ID)
+}
+
+pub fn mint_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mint_authority"], &carbon_credits::ID)
+}
+
+pub fn treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury"], &carbon_credits::ID)
+}
+
+pub fn treasury_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"treasury_authority"], &carbon_credits::ID)
+}
+
+pub fn industry(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"industry", authority.as_ref()], &carbon_credits::ID)
+}
+
+pub fn bond_vault(industry: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"bond_vault", industry.as_ref()], &carbon_credits::ID)
+}
+
+pub fn bond_vault_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"bond_vault_authority"], &carbon_credits::ID)
+}
+
+/// `credential_count` is the industry's count *before* the credential was issued
+pub fn credential_mint(industry: &Pubkey, credential_count: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"credential",
+            industry.as_ref(),
+            credential_count.to_le_bytes().as_ref(),
+        ],
+        &carbon_credits::ID,
+    )
+}
+
+pub fn credential_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"credential_authority"], &carbon_credits::ID)
+}
+
+/// Token Metadata account of a credential mint
+pub fn metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    let program_id = anchor_spl::metadata::ID;
+    Pubkey::find_program_address(
+        &[b"metadata", program_id.as_ref(), mint.as_ref()],
+        &program_id,
+    )
+}
+
+/// Token Metadata master edition of a credential mint
+pub fn master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    let program_id = anchor_spl::metadata::ID;
+    Pubkey::find_program_address(
+        &[b"metadata", program_id.as_ref(), mint.as_ref(), b"edition"],
+        &program_id,
+    )
+}
+
+/// `report_period` must be canonical (see `carbon_credits::normalize_report_period`)
+pub fn emission_report(industry: &Pubkey, report_period: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"emission_report",
+            industry.as_ref(),
+            report_period.as_bytes(),
+        ],
+        &carbon_credits::ID,
+    )
+}
+
+pub fn emission_report_revision(report: &Pubkey, version: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"emission_report_revision",
+            report.as_ref(),
+            version.to_le_bytes().as_ref(),
+        ],
+        &carbon_credits::ID,
+    )
+}
+
+pub fn auditor(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"auditor", authority.as_ref()], &carbon_credits::ID)
+}
+
+pub fn compliance_period(period_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"compliance_period", period_id.to_le_bytes().as_ref()],
+        &carbon_credits::ID,
+    )
+}
+
+pub fn obligation(period: &Pubkey, industry: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"obligation", period.as_ref(), industry.as_ref()],
+        &carbon_credits::ID,
+    )
+}
+
+// ----------------------------------------------------------------------------
+// dutch-auction
+// ----------------------------------------------------------------------------
+
+/// Standalone batch created with `create_auction` or `create_sealed_auction`
+pub fn auction(batch_number: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"auction", batch_number.to_le_bytes().as_ref()],
+        &dutch_auction::ID,
+    )
+}
+
+/// `timestamp` is the cluster clock at the slot the bid lands in
+pub fn bid(auction: &Pubkey, bidder: &Pubkey, timestamp: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"bid",
+            auction.as_ref(),
+            bidder.as_ref(),
+            timestamp.to_le_bytes().as_ref(),
+        ],
+        &dutch_auction::ID,
+    )
+}
+
+/// Batch `batch_number` of `series`, as launched by `launch_next_batch`
+pub fn series_auction(series: &Pubkey, batch_number: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"auction",
+            series.as_ref(),
+            batch_number.to_le_bytes().as_ref(),
+        ],
+        &dutch_auction::ID,
+    )
+}
+
+/// Recurring batches launched with `launch_next_batch`
+pub fn auction_series(series_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"series", series_id.to_le_bytes().as_ref()],
+        &dutch_auction::ID,
+    )
+}
+
+/// Running totals of one bidder in one auction
+pub fn bidder_position(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"position", auction.as_ref(), bidder.as_ref()],
+        &dutch_auction::ID,
+    )
+}
+
+/// One resting limit order per bidder per auction
+pub fn limit_order(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"limit_order", auction.as_ref(), bidder.as_ref()],
+        &dutch_auction::ID,
+    )
+}
+
+/// One sealed-bid commitment per bidder per auction
+pub fn sealed_bid(auction: &Pubkey, bidder: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"sealed_bid", auction.as_ref(), bidder.as_ref()],
+        &dutch_auction::ID,
+    )
+}
+
+pub fn sealed_book(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"sealed_book", auction.as_ref()], &dutch_auction::ID)
+}
+
+pub fn escrow(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow", auction.as_ref()], &dutch_auction::ID)
+}
+
+pub fn escrow_authority(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow_authority", auction.as_ref()], &dutch_auction::ID)
+}
+
+/// Register this with `carbon_credits::set_auction_issuer`
+pub fn cct_issuer() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"cct_issuer"], &dutch_auction::ID)
+}
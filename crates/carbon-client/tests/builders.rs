@@ -0,0 +1,172 @@
+use anchor_lang::{AccountSerialize, AnchorDeserialize, Discriminator};
+use carbon_client::accounts::{self, Auction, AuctionSeries, IndustryAccount};
+use carbon_client::carbon_credits::{ComplianceStatus, VerificationStatus};
+use carbon_client::dutch_auction::{BidLimits, ExtensionPolicy, PriceCurve, SeriesTemplate};
+use carbon_client::{auction, credits, get_associated_token_address, pda, Pubkey};
+
+fn industry_account(authority: Pubkey) -> IndustryAccount {
+    IndustryAccount {
+        authority,
+        company_name: "Acme".to_string(),
+        bond_amount: 1_000,
+        verification_status: VerificationStatus::Verified,
+        status_reason: 0,
+        status_updated_at: 0,
+        cct_balance: 0,
+        total_purchased: 0,
+        total_burned: 0,
+        compliance_status: ComplianceStatus::Compliant,
+        created_at: 0,
+        non_compliant_since: 0,
+        total_slashed: 0,
+        slash_count: 0,
+        last_slashed_at: 0,
+        credential_mint: Pubkey::new_unique(),
+        credential_count: 1,
+        verified_by: Pubkey::new_unique(),
+        verified_at: 0,
+    }
+}
+
+#[test]
+fn builder_derives_industry_pdas_and_encodes_args() {
+    let authority = Pubkey::new_unique();
+    let user_usdc = Pubkey::new_unique();
+    let ix = credits::deposit_bond(authority, user_usdc, 42);
+
+    let industry = pda::industry(&authority).0;
+    let keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(ix.program_id, carbon_client::carbon_credits::ID);
+    assert_eq!(
+        keys,
+        vec![
+            pda::config().0,
+            industry,
+            user_usdc,
+            pda::bond_vault(&industry).0,
+            authority,
+            anchor_spl::token::ID,
+        ]
+    );
+    assert!(ix.accounts[4].is_signer);
+
+    let discriminator = carbon_client::carbon_credits::instruction::DepositBond::DISCRIMINATOR;
+    assert_eq!(&ix.data[..8], discriminator.as_ref());
+    assert_eq!(u64::try_from_slice(&ix.data[8..]).unwrap(), 42);
+}
+
+#[test]
+fn credential_builders_bind_the_holder_token_account() {
+    let verifier = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let industry = pda::industry(&authority).0;
+    let credential_mint = pda::credential_mint(&industry, 0).0;
+    let credential_account = get_associated_token_address(&authority, &credential_mint);
+
+    // The holder co-signs verification to delegate the credential for revocation
+    let verify = credits::verify_industry(verifier, authority, 0, "uri".to_string());
+    assert_eq!(verify.accounts[2].pubkey, authority);
+    assert!(verify.accounts[2].is_signer);
+    assert_eq!(verify.accounts[4].pubkey, credential_account);
+
+    let revoke = credits::revoke_industry(verifier, authority, credential_mint, 1);
+    let keys: Vec<Pubkey> = revoke.accounts.iter().map(|meta| meta.pubkey).collect();
+    assert_eq!(keys[2], credential_mint);
+    assert_eq!(keys[3], credential_account);
+    assert_eq!(keys[5], pda::master_edition(&credential_mint).0);
+    assert!(revoke.accounts[2].is_writable && revoke.accounts[3].is_writable);
+}
+
+#[test]
+fn auction_builders_share_batch_pdas() {
+    let authority = Pubkey::new_unique();
+    let create = auction::create_auction(
+        authority,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        auction::CreateAuctionArgs {
+            batch_number: 7,
+            total_tokens: 1_000,
+            start_price: 100,
+            reserve_price: 10,
+            start_time: 0,
+            duration_seconds: 3_600,
+            price_curve: PriceCurve::Linear,
+            bid_limits: BidLimits::default(),
+            extension: ExtensionPolicy::default(),
+            min_raise: 0,
+        },
+    );
+    let auction_key = pda::auction(7).0;
+    let withdraw = auction::withdraw_proceeds(authority, auction_key, Pubkey::new_unique());
+
+    assert_eq!(create.accounts[0].pubkey, auction_key);
+    assert_eq!(withdraw.accounts[0].pubkey, auction_key);
+    assert_eq!(create.accounts[3].pubkey, pda::escrow(&auction_key).0);
+    assert_eq!(withdraw.accounts[1].pubkey, pda::escrow(&auction_key).0);
+}
+
+#[test]
+fn launch_next_batch_passes_the_previous_batch_once_one_exists() {
+    let mut series = AuctionSeries {
+        authority: Pubkey::new_unique(),
+        series_id: 3,
+        next_batch_number: 10,
+        batches_launched: 0,
+        supply_budget: 10_000,
+        supply_sold: 0,
+        tokens_per_batch: 1_000,
+        template: SeriesTemplate {
+            start_price: 100,
+            reserve_price: 10,
+            duration_seconds: 3_600,
+            price_curve: PriceCurve::Linear,
+            bid_limits: BidLimits::default(),
+            extension: ExtensionPolicy::default(),
+            min_raise: 0,
+        },
+        adaptive_start_bps: None,
+        usdc_mint: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+    };
+
+    let series_key = pda::auction_series(3).0;
+    let first = auction::launch_next_batch(series.authority, &series, 0);
+    assert_eq!(first.accounts[0].pubkey, series_key);
+    // Absent optional accounts are passed as the program id
+    assert_eq!(first.accounts[1].pubkey, carbon_client::dutch_auction::ID);
+    assert_eq!(
+        first.accounts[2].pubkey,
+        pda::series_auction(&series_key, 10).0
+    );
+    // Series batches never share an address with the standalone batch of the same number
+    assert_ne!(first.accounts[2].pubkey, pda::auction(10).0);
+
+    series.next_batch_number = 11;
+    series.batches_launched = 1;
+    let second = auction::launch_next_batch(series.authority, &series, 0);
+    assert_eq!(
+        second.accounts[1].pubkey,
+        pda::series_auction(&series_key, 10).0
+    );
+    assert_eq!(
+        second.accounts[2].pubkey,
+        pda::series_auction(&series_key, 11).0
+    );
+}
+
+#[test]
+fn decoders_round_trip_and_reject_foreign_accounts() {
+    let authority = Pubkey::new_unique();
+    let mut data = Vec::new();
+    industry_account(authority)
+        .try_serialize(&mut data)
+        .unwrap();
+
+    let decoded = accounts::industry_account(&data).unwrap();
+    assert_eq!(decoded.authority, authority);
+    assert!(decoded.verification_status == VerificationStatus::Verified);
+
+    assert!(accounts::auction(&data).is_err());
+    assert!(accounts::decode::<Auction>(&data[..4]).is_err());
+}
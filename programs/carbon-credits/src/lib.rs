@@ -1,53 +1,535 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, Burn};
+use anchor_spl::token::{self, Approve, Mint, Token, TokenAccount, MintTo, Burn};
 use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    self,
+    mpl_token_metadata::{
+        self,
+        types::{Creator, DataV2},
+    },
+    Metadata, MetadataAccount,
+};
 
 declare_id!("CCTxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
 
+/// 1 CCT expressed in base units (the mint uses 9 decimals)
+pub const CCT_UNIT: u64 = 1_000_000_000;
+
+/// Maximum number of verifiers that can be registered in the program config
+pub const MAX_VERIFIERS: usize = 10;
+
+/// Basis point denominator used for percentage tunables
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Metadata symbol carried by verification credential NFTs
+pub const CREDENTIAL_SYMBOL: &str = "CCTV";
+
+/// Name prefix written into a credential's metadata while the industry is suspended
+pub const CREDENTIAL_SUSPENDED_PREFIX: &str = "SUSPENDED ";
+
+/// Name prefix written into a credential's metadata when verification is revoked
+pub const CREDENTIAL_REVOKED_PREFIX: &str = "REVOKED ";
+
 #[program]
 pub mod carbon_credits {
     use super::*;
 
-    /// Initialize the CCT token mint
-    pub fn initialize_mint(ctx: Context<InitializeMint>) -> Result<()> {
-        msg!("CCT Token Mint initialized");
+    /// Initialize the CCT token mint, program config and USDC treasury
+    pub fn initialize_mint(
+        ctx: Context<InitializeMint>,
+        min_bond: u64,
+        cct_price: u64,
+    ) -> Result<()> {
+        require!(cct_price > 0, ErrorCode::InvalidPrice);
+
+        let config = &mut ctx.accounts.config;
+
+        config.admin = ctx.accounts.authority.key();
+        config.pending_admin = None;
+        config.verifiers = Vec::new();
+        config.cct_mint = ctx.accounts.cct_mint.key();
+        config.usdc_mint = ctx.accounts.usdc_mint.key();
+        config.treasury = ctx.accounts.treasury.key();
+        config.auction_issuer = Pubkey::default();
+        config.penalty_treasury = ctx.accounts.treasury.key();
+        config.min_bond = min_bond;
+        config.cct_price = cct_price;
+        config.slash_bps = 0;
+        config.slash_grace_period = 0;
+        config.bump = ctx.bumps.config;
+
+        msg!("CCT Token Mint initialized. Price: {} USDC/CCT", cct_price);
+        Ok(())
+    }
+
+    /// Update the USDC price charged per CCT by `purchase_cct`
+    pub fn update_cct_price(ctx: Context<UpdateConfig>, cct_price: u64) -> Result<()> {
+        require!(cct_price > 0, ErrorCode::InvalidPrice);
+
+        ctx.accounts.config.cct_price = cct_price;
+
+        msg!("CCT price updated: {} USDC/CCT", cct_price);
+        Ok(())
+    }
+
+    /// Update the minimum bond required to register an industry
+    pub fn update_min_bond(ctx: Context<UpdateConfig>, min_bond: u64) -> Result<()> {
+        ctx.accounts.config.min_bond = min_bond;
+
+        msg!("Minimum bond updated: {} USDC", min_bond);
+        Ok(())
+    }
+
+    /// Configure bond slashing: share of bond slashed, grace period and penalty treasury
+    pub fn update_slash_policy(
+        ctx: Context<UpdateSlashPolicy>,
+        slash_bps: u16,
+        slash_grace_period: i64,
+    ) -> Result<()> {
+        require!(
+            slash_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidSlashBps
+        );
+        require!(slash_grace_period >= 0, ErrorCode::InvalidGracePeriod);
+
+        let config = &mut ctx.accounts.config;
+
+        config.slash_bps = slash_bps;
+        config.slash_grace_period = slash_grace_period;
+        config.penalty_treasury = ctx.accounts.penalty_treasury.key();
+
+        msg!(
+            "Slash policy updated: {} bps after {}s grace",
+            slash_bps,
+            slash_grace_period
+        );
+        Ok(())
+    }
+
+    /// Add a key to the verifier set
+    pub fn add_verifier(ctx: Context<UpdateConfig>, verifier: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        require!(
+            !config.verifiers.contains(&verifier),
+            ErrorCode::VerifierAlreadyExists
+        );
+        require!(
+            config.verifiers.len() < MAX_VERIFIERS,
+            ErrorCode::TooManyVerifiers
+        );
+
+        config.verifiers.push(verifier);
+
+        msg!("Verifier added: {}", verifier);
+        Ok(())
+    }
+
+    /// Remove a key from the verifier set
+    pub fn remove_verifier(ctx: Context<UpdateConfig>, verifier: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        let index = config
+            .verifiers
+            .iter()
+            .position(|v| *v == verifier)
+            .ok_or(ErrorCode::VerifierNotFound)?;
+        config.verifiers.remove(index);
+
+        msg!("Verifier removed: {}", verifier);
+        Ok(())
+    }
+
+    /// Set the dutch-auction PDA allowed to issue CCT to auction winners
+    pub fn set_auction_issuer(ctx: Context<UpdateConfig>, issuer: Pubkey) -> Result<()> {
+        ctx.accounts.config.auction_issuer = issuer;
+
+        msg!("Auction issuer set: {}", issuer);
+        Ok(())
+    }
+
+    /// Propose a new admin (step 1 of the handover)
+    pub fn propose_admin(ctx: Context<UpdateConfig>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.config.pending_admin = Some(new_admin);
+
+        msg!("Admin handover proposed to {}", new_admin);
+        Ok(())
+    }
+
+    /// Accept a pending admin proposal (step 2 of the handover)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+
+        require!(
+            config.pending_admin == Some(ctx.accounts.new_admin.key()),
+            ErrorCode::NotPendingAdmin
+        );
+
+        config.admin = ctx.accounts.new_admin.key();
+        config.pending_admin = None;
+
+        msg!("Admin handover accepted by {}", config.admin);
         Ok(())
     }
 
-    /// Register a new industry with verification NFT
+    /// Register a new industry and lock its initial bond in a per-industry vault
     pub fn register_industry(
         ctx: Context<RegisterIndustry>,
         company_name: String,
         bond_amount: u64,
     ) -> Result<()> {
-        let industry = &mut ctx.accounts.industry_account;
+        require!(
+            bond_amount >= ctx.accounts.config.min_bond,
+            ErrorCode::InsufficientBond
+        );
+
+        // Transfer the initial bond into the industry's vault
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.user_usdc.to_account_info(),
+                    to: ctx.accounts.bond_vault.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            bond_amount,
+        )?;
 
-        require!(bond_amount >= 1_000_000_000, ErrorCode::InsufficientBond); // Min 1000 USDC
+        let industry = &mut ctx.accounts.industry_account;
 
         industry.authority = ctx.accounts.authority.key();
         industry.company_name = company_name;
         industry.bond_amount = bond_amount;
-        industry.verified = false;
+        industry.verification_status = VerificationStatus::Unverified;
+        industry.status_reason = 0;
+        industry.status_updated_at = 0;
         industry.cct_balance = 0;
         industry.total_purchased = 0;
         industry.total_burned = 0;
         industry.compliance_status = ComplianceStatus::Pending;
         industry.created_at = Clock::get()?.unix_timestamp;
+        industry.non_compliant_since = 0;
+        industry.total_slashed = 0;
+        industry.slash_count = 0;
+        industry.last_slashed_at = 0;
+        industry.credential_mint = Pubkey::default();
+        industry.credential_count = 0;
+        industry.verified_by = Pubkey::default();
+        industry.verified_at = 0;
+
+        emit!(IndustryRegistered {
+            industry: industry.key(),
+            authority: industry.authority,
+            company_name: industry.company_name.clone(),
+            bond_amount,
+            timestamp: industry.created_at,
+        });
 
         msg!("Industry registered: {}", industry.company_name);
         Ok(())
     }
 
     /// Verify an industry and mint verification NFT
-    pub fn verify_industry(ctx: Context<VerifyIndustry>) -> Result<()> {
+    ///
+    /// The credential is a 1-of-1 Metaplex NFT held by the industry authority, who co-signs to
+    /// delegate it to `credential_authority`. Its token account is frozen before the master
+    /// edition takes over the mint and freeze authorities, so it cannot be transferred; only
+    /// `revoke_industry` thaws it, as the delegate, to burn it.
+    pub fn verify_industry(ctx: Context<VerifyIndustry>, metadata_uri: String) -> Result<()> {
+        // Fresh registrations and revoked industries may be (re-)verified
+        require!(
+            matches!(
+                ctx.accounts.industry_account.verification_status,
+                VerificationStatus::Unverified | VerificationStatus::Revoked
+            ),
+            ErrorCode::AlreadyVerified
+        );
+        require!(
+            metadata_uri.len() <= mpl_token_metadata::MAX_URI_LENGTH,
+            ErrorCode::InvalidMetadataUri
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let seeds = &[
+            b"credential_authority".as_ref(),
+            &[ctx.bumps.credential_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.credential_mint.to_account_info(),
+                    to: ctx.accounts.credential_account.to_account_info(),
+                    authority: ctx.accounts.credential_authority.to_account_info(),
+                },
+                signer,
+            ),
+            1,
+        )?;
+
+        // The holder delegates the credential to our PDA so revocation can burn it
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.credential_account.to_account_info(),
+                    delegate: ctx.accounts.credential_authority.to_account_info(),
+                    authority: ctx.accounts.industry_authority.to_account_info(),
+                },
+            ),
+            1,
+        )?;
+
+        // Soulbound: freeze the holder's account while we still own the freeze authority
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::FreezeAccount {
+                account: ctx.accounts.credential_account.to_account_info(),
+                mint: ctx.accounts.credential_mint.to_account_info(),
+                authority: ctx.accounts.credential_authority.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        let industry = &ctx.accounts.industry_account;
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                metadata::CreateMetadataAccountsV3 {
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    mint: ctx.accounts.credential_mint.to_account_info(),
+                    mint_authority: ctx.accounts.credential_authority.to_account_info(),
+                    payer: ctx.accounts.verifier.to_account_info(),
+                    update_authority: ctx.accounts.credential_authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer,
+            ),
+            DataV2 {
+                name: credential_name("", &industry.company_name, now),
+                symbol: CREDENTIAL_SYMBOL.to_string(),
+                uri: metadata_uri,
+                seller_fee_basis_points: 0,
+                creators: Some(credential_creators(
+                    ctx.accounts.credential_authority.key(),
+                    ctx.accounts.verifier.key(),
+                )),
+                collection: None,
+                uses: None,
+            },
+            true,
+            true,
+            None,
+        )?;
+
+        metadata::create_master_edition_v3(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_metadata_program.to_account_info(),
+                metadata::CreateMasterEditionV3 {
+                    edition: ctx.accounts.master_edition.to_account_info(),
+                    mint: ctx.accounts.credential_mint.to_account_info(),
+                    update_authority: ctx.accounts.credential_authority.to_account_info(),
+                    mint_authority: ctx.accounts.credential_authority.to_account_info(),
+                    payer: ctx.accounts.verifier.to_account_info(),
+                    metadata: ctx.accounts.metadata.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                signer,
+            ),
+            Some(0),
+        )?;
+
+        let industry = &mut ctx.accounts.industry_account;
+
+        // A revoked industry keeps its compliance record, so re-verifying cannot clear a
+        // NonCompliant status that gates bond withdrawal and slashing
+        if industry.verification_status == VerificationStatus::Unverified {
+            industry.compliance_status = ComplianceStatus::Compliant;
+        }
+        industry.verification_status = VerificationStatus::Verified;
+        industry.status_reason = 0;
+        industry.status_updated_at = now;
+        industry.credential_mint = ctx.accounts.credential_mint.key();
+        industry.credential_count += 1;
+        industry.verified_by = ctx.accounts.verifier.key();
+        industry.verified_at = now;
+
+        emit!(IndustryVerified {
+            industry: industry.key(),
+            verifier: industry.verified_by,
+            credential_mint: industry.credential_mint,
+            credential_count: industry.credential_count,
+            timestamp: now,
+        });
+
+        msg!(
+            "Industry verified: {} (credential {})",
+            industry.company_name,
+            industry.credential_mint
+        );
+        Ok(())
+    }
+
+    /// Suspend a verified industry pending investigation (admin)
+    ///
+    /// Suspended industries keep their history and can still top up their bond and burn CCT
+    /// against open obligations, but cannot acquire new CCT or withdraw bond.
+    pub fn suspend_industry(ctx: Context<SetVerificationStatus>, reason_code: u16) -> Result<()> {
+        let industry = &ctx.accounts.industry_account;
+
+        industry.ensure_in_standing(false)?;
+
+        update_credential_metadata(
+            &ctx.accounts.token_metadata_program,
+            &ctx.accounts.metadata,
+            &ctx.accounts.credential_authority,
+            ctx.bumps.credential_authority,
+            credential_name(
+                CREDENTIAL_SUSPENDED_PREFIX,
+                &industry.company_name,
+                industry.verified_at,
+            ),
+            false,
+        )?;
+
+        let industry = &mut ctx.accounts.industry_account;
+
+        industry.verification_status = VerificationStatus::Suspended;
+        industry.status_reason = reason_code;
+        industry.status_updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(IndustryStatusChanged {
+            industry: industry.key(),
+            status: industry.verification_status.clone(),
+            reason_code,
+            timestamp: industry.status_updated_at,
+        });
+
+        msg!(
+            "Industry suspended: {} (reason {})",
+            industry.company_name,
+            reason_code
+        );
+        Ok(())
+    }
+
+    /// Lift a suspension and restore the industry's credential (admin)
+    pub fn reinstate_industry(ctx: Context<SetVerificationStatus>) -> Result<()> {
+        let industry = &ctx.accounts.industry_account;
+
+        require!(
+            industry.verification_status == VerificationStatus::Suspended,
+            ErrorCode::NotSuspended
+        );
+
+        update_credential_metadata(
+            &ctx.accounts.token_metadata_program,
+            &ctx.accounts.metadata,
+            &ctx.accounts.credential_authority,
+            ctx.bumps.credential_authority,
+            credential_name("", &industry.company_name, industry.verified_at),
+            false,
+        )?;
+
         let industry = &mut ctx.accounts.industry_account;
 
-        require!(!industry.verified, ErrorCode::AlreadyVerified);
+        industry.verification_status = VerificationStatus::Verified;
+        industry.status_reason = 0;
+        industry.status_updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(IndustryStatusChanged {
+            industry: industry.key(),
+            status: industry.verification_status.clone(),
+            reason_code: 0,
+            timestamp: industry.status_updated_at,
+        });
+
+        msg!("Industry reinstated: {}", industry.company_name);
+        Ok(())
+    }
+
+    /// Revoke an industry's verification and burn its credential NFT (admin)
+    ///
+    /// The credential stays frozen in the holder's wallet; its metadata is renamed and
+    /// locked so wallets and partners can see it no longer stands. The industry account and
+    /// its history are kept, and `verify_industry` may later issue a fresh credential.
+    pub fn revoke_industry(ctx: Context<RevokeIndustry>, reason_code: u16) -> Result<()> {
+        let industry = &ctx.accounts.industry_account;
+
+        industry.ensure_in_standing(true)?;
+
+        update_credential_metadata(
+            &ctx.accounts.token_metadata_program,
+            &ctx.accounts.metadata,
+            &ctx.accounts.credential_authority,
+            ctx.bumps.credential_authority,
+            credential_name(
+                CREDENTIAL_REVOKED_PREFIX,
+                &industry.company_name,
+                industry.verified_at,
+            ),
+            true,
+        )?;
+
+        let seeds = &[
+            b"credential_authority".as_ref(),
+            &[ctx.bumps.credential_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        // The master edition holds the freeze authority; as the holder's delegate we may thaw
+        metadata::thaw_delegated_account(CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            metadata::ThawDelegatedAccount {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                delegate: ctx.accounts.credential_authority.to_account_info(),
+                token_account: ctx.accounts.credential_account.to_account_info(),
+                edition: ctx.accounts.master_edition.to_account_info(),
+                mint: ctx.accounts.credential_mint.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            signer,
+        ))?;
+
+        // Burn it, so the wallet no longer holds anything that looks like a valid credential
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.credential_mint.to_account_info(),
+                    from: ctx.accounts.credential_account.to_account_info(),
+                    authority: ctx.accounts.credential_authority.to_account_info(),
+                },
+                signer,
+            ),
+            1,
+        )?;
 
-        industry.verified = true;
-        industry.compliance_status = ComplianceStatus::Compliant;
+        let industry = &mut ctx.accounts.industry_account;
 
-        msg!("Industry verified: {}", industry.company_name);
+        industry.verification_status = VerificationStatus::Revoked;
+        industry.status_reason = reason_code;
+        industry.status_updated_at = Clock::get()?.unix_timestamp;
+        industry.credential_mint = Pubkey::default();
+
+        emit!(IndustryStatusChanged {
+            industry: industry.key(),
+            status: industry.verification_status.clone(),
+            reason_code,
+            timestamp: industry.status_updated_at,
+        });
+
+        msg!(
+            "Industry revoked: {} (reason {})",
+            industry.company_name,
+            reason_code
+        );
         Ok(())
     }
 
@@ -55,7 +537,7 @@ pub mod carbon_credits {
     pub fn deposit_bond(ctx: Context<DepositBond>, amount: u64) -> Result<()> {
         let industry = &mut ctx.accounts.industry_account;
 
-        require!(industry.verified, ErrorCode::NotVerified);
+        industry.ensure_in_standing(true)?;
 
         // Transfer USDC to bond vault
         token::transfer(
@@ -72,19 +554,51 @@ pub mod carbon_credits {
 
         industry.bond_amount += amount;
 
+        emit!(BondDeposited {
+            industry: industry.key(),
+            amount,
+            bond_amount: industry.bond_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         msg!("Bond deposited: {} USDC", amount);
         Ok(())
     }
 
-    /// Purchase CCT tokens (mint to industry)
-    pub fn purchase_cct(ctx: Context<PurchaseCCT>, amount: u64) -> Result<()> {
+    /// Purchase CCT tokens (USDC paid into treasury, CCT minted to industry)
+    pub fn purchase_cct(
+        ctx: Context<PurchaseCCT>,
+        amount: u64,
+        max_price_per_token: u64,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
         let industry = &mut ctx.accounts.industry_account;
 
-        require!(industry.verified, ErrorCode::NotVerified);
+        industry.ensure_in_standing(false)?;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            config.cct_price <= max_price_per_token,
+            ErrorCode::SlippageExceeded
+        );
+
+        let cost = calculate_purchase_cost(amount, config.cct_price)?;
+
+        // Transfer USDC payment to treasury before minting
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.user_usdc.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
 
         // Mint CCT tokens to industry's account
         let seeds = &[
-            b"mint_authority",
+            b"mint_authority".as_ref(),
             &[ctx.bumps.mint_authority],
         ];
         let signer = &[&seeds[..]];
@@ -105,20 +619,67 @@ pub mod carbon_credits {
         industry.cct_balance += amount;
         industry.total_purchased += amount;
 
-        msg!("CCT purchased: {} tokens", amount);
+        emit!(CctPurchased {
+            industry: industry.key(),
+            amount,
+            price: config.cct_price,
+            cost,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("CCT purchased: {} tokens for {} USDC", amount, cost);
         Ok(())
     }
 
-    /// Burn CCT tokens for compliance
-    pub fn burn_cct_for_compliance(
-        ctx: Context<BurnCCT>,
-        amount: u64,
-        emission_amount: u64,
-    ) -> Result<()> {
+    /// Issue CCT won in an auction (CPI from dutch-auction, signed by its issuer PDA)
+    pub fn issue_auction_cct(ctx: Context<IssueAuctionCCT>, amount: u64) -> Result<()> {
+        let industry = &mut ctx.accounts.industry_account;
+
+        industry.ensure_can_settle_auction()?;
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let seeds = &[
+            b"mint_authority".as_ref(),
+            &[ctx.bumps.mint_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.cct_mint.to_account_info(),
+                    to: ctx.accounts.industry_cct_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        industry.cct_balance += amount;
+        industry.total_purchased += amount;
+
+        emit!(AuctionCctIssued {
+            industry: industry.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Auction CCT issued: {} tokens", amount);
+        Ok(())
+    }
+
+    /// Burn CCT tokens, surrendering them against the industry's obligation for a period
+    pub fn burn_cct_for_compliance(ctx: Context<BurnCCT>, amount: u64) -> Result<()> {
+        let period = &ctx.accounts.compliance_period;
+        let obligation = &mut ctx.accounts.obligation;
         let industry = &mut ctx.accounts.industry_account;
+        let now = Clock::get()?.unix_timestamp;
 
-        require!(industry.verified, ErrorCode::NotVerified);
+        industry.ensure_in_standing(true)?;
         require!(industry.cct_balance >= amount, ErrorCode::InsufficientCCT);
+        period.ensure_open(now)?;
 
         // Burn CCT tokens
         token::burn(
@@ -135,49 +696,373 @@ pub mod carbon_credits {
 
         industry.cct_balance -= amount;
         industry.total_burned += amount;
+        obligation.cct_surrendered = obligation
+            .cct_surrendered
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(CctBurned {
+            industry: industry.key(),
+            period: period.key(),
+            obligation: obligation.key(),
+            amount,
+            cct_surrendered: obligation.cct_surrendered,
+            timestamp: now,
+        });
 
-        // Check compliance
-        if amount >= emission_amount {
-            industry.compliance_status = ComplianceStatus::Compliant;
-        } else {
-            industry.compliance_status = ComplianceStatus::NonCompliant;
+        msg!(
+            "CCT burned for compliance: {} tokens ({} period)",
+            amount,
+            period.label
+        );
+        Ok(())
+    }
+
+    /// Open a compliance period (e.g. a calendar year) with a surrender deadline. The label is
+    /// stored in canonical report-period form ("2025-q3" becomes "2025-Q3").
+    pub fn create_compliance_period(
+        ctx: Context<CreateCompliancePeriod>,
+        period_id: u32,
+        label: String,
+        start_time: i64,
+        deadline: i64,
+        warning_bps: u16,
+    ) -> Result<()> {
+        require!(deadline > start_time, ErrorCode::InvalidPeriod);
+        require!(
+            warning_bps as u64 <= BPS_DENOMINATOR,
+            ErrorCode::InvalidWarningBps
+        );
+        // Reports are stored canonically, so the label must be too or `period_covers` never
+        // matches them
+        let label = normalize_report_period(&label).ok_or(ErrorCode::InvalidReportPeriod)?;
+
+        let period = &mut ctx.accounts.compliance_period;
+
+        period.period_id = period_id;
+        period.label = label;
+        period.start_time = start_time;
+        period.deadline = deadline;
+        period.warning_bps = warning_bps;
+
+        emit!(CompliancePeriodCreated {
+            period: period.key(),
+            period_id,
+            label: period.label.clone(),
+            start_time,
+            deadline,
+            warning_bps,
+        });
+
+        msg!(
+            "Compliance period {} opened, deadline {}",
+            period.label,
+            deadline
+        );
+        Ok(())
+    }
+
+    /// Open an industry's obligation record for a period (permissionless)
+    pub fn open_compliance_obligation(ctx: Context<OpenComplianceObligation>) -> Result<()> {
+        let obligation = &mut ctx.accounts.obligation;
+
+        obligation.industry = ctx.accounts.industry_account.key();
+        obligation.period = ctx.accounts.compliance_period.key();
+        obligation.emissions_reported = 0;
+        obligation.reports_linked = 0;
+        obligation.cct_surrendered = 0;
+        obligation.status = ComplianceStatus::Pending;
+        obligation.evaluated_at = 0;
+
+        emit!(ComplianceObligationOpened {
+            obligation: obligation.key(),
+            industry: obligation.industry,
+            period: obligation.period,
+        });
+
+        msg!(
+            "Compliance obligation opened for {}",
+            ctx.accounts.industry_account.company_name
+        );
+        Ok(())
+    }
+
+    /// Count a verified emission report towards an obligation (permissionless)
+    pub fn record_emission_report(ctx: Context<RecordEmissionReport>) -> Result<()> {
+        let period = &ctx.accounts.compliance_period;
+        let obligation = &mut ctx.accounts.obligation;
+        let report = &mut ctx.accounts.emission_report;
+
+        require!(report.verified, ErrorCode::ReportNotVerified);
+        require!(
+            period_covers(&period.label, &report.report_period),
+            ErrorCode::ReportPeriodMismatch
+        );
+        require!(obligation.evaluated_at == 0, ErrorCode::AlreadyEvaluated);
+        require!(
+            report.counted_in == Pubkey::default() || report.counted_in == obligation.key(),
+            ErrorCode::ReportCountedElsewhere
+        );
+
+        // Re-recording a report applies only the difference since it was last counted
+        if report.counted_in == Pubkey::default() {
+            obligation.reports_linked += 1;
         }
+        obligation.emissions_reported = obligation
+            .emissions_reported
+            .checked_sub(report.counted_co2)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(report.co2_emitted)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        report.counted_in = obligation.key();
+        report.counted_co2 = report.co2_emitted;
+
+        emit!(EmissionReportRecorded {
+            report: report.key(),
+            obligation: obligation.key(),
+            co2_emitted: report.co2_emitted,
+            emissions_reported: obligation.emissions_reported,
+        });
+
+        msg!(
+            "Emission report recorded: {} tonnes CO2 ({} period total: {})",
+            report.co2_emitted,
+            period.label,
+            obligation.emissions_reported
+        );
+        Ok(())
+    }
 
-        msg!("CCT burned for compliance: {} tokens", amount);
+    /// Evaluate an obligation once the period deadline has passed (permissionless)
+    pub fn evaluate_compliance(ctx: Context<EvaluateCompliance>) -> Result<()> {
+        let period = &ctx.accounts.compliance_period;
+        let obligation = &mut ctx.accounts.obligation;
+        let industry = &mut ctx.accounts.industry_account;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(now > period.deadline, ErrorCode::PeriodNotEnded);
+        require!(obligation.evaluated_at == 0, ErrorCode::AlreadyEvaluated);
+
+        let status = evaluate_obligation(
+            obligation.reports_linked,
+            obligation.emissions_reported,
+            obligation.cct_surrendered,
+            period.warning_bps,
+        )?;
+
+        if status == ComplianceStatus::NonCompliant
+            && industry.compliance_status != ComplianceStatus::NonCompliant
+        {
+            industry.non_compliant_since = now;
+        }
+        industry.compliance_status = status.clone();
+        obligation.status = status;
+        obligation.evaluated_at = now;
+
+        emit!(ComplianceEvaluated {
+            industry: industry.key(),
+            period: period.key(),
+            obligation: obligation.key(),
+            status: obligation.status.clone(),
+            emissions_reported: obligation.emissions_reported,
+            cct_surrendered: obligation.cct_surrendered,
+            timestamp: now,
+        });
+
+        msg!(
+            "Compliance evaluated for {} ({} period): {} CCT surrendered against {} tonnes",
+            industry.company_name,
+            period.label,
+            obligation.cct_surrendered,
+            obligation.emissions_reported
+        );
         Ok(())
     }
 
-    /// Submit emission report
+    /// Submit emission report (one per industry and canonical period, e.g. "2025-Q3")
     pub fn submit_emission_report(
         ctx: Context<SubmitEmissionReport>,
         co2_emitted: u64,
         report_period: String,
     ) -> Result<()> {
+        require!(
+            normalize_report_period(&report_period).as_deref() == Some(report_period.as_str()),
+            ErrorCode::InvalidReportPeriod
+        );
+
         let report = &mut ctx.accounts.emission_report;
 
         report.industry = ctx.accounts.industry_account.key();
+        report.version = 1;
         report.co2_emitted = co2_emitted;
+        report.reported_co2 = co2_emitted;
         report.report_period = report_period;
         report.submitted_at = Clock::get()?.unix_timestamp;
         report.verified = false;
+        report.verified_by = Pubkey::default();
+        report.verified_at = 0;
+        report.evidence_hash = [0; 32];
+        report.counted_in = Pubkey::default();
+        report.counted_co2 = 0;
+
+        emit!(EmissionReportSubmitted {
+            report: report.key(),
+            industry: report.industry,
+            report_period: report.report_period.clone(),
+            co2_emitted,
+            timestamp: report.submitted_at,
+        });
 
         msg!("Emission report submitted: {} tonnes CO2", co2_emitted);
         Ok(())
     }
 
-    /// Withdraw bond (only if exiting program)
-    pub fn withdraw_bond(ctx: Context<WithdrawBond>, amount: u64) -> Result<()> {
-        let industry = &mut ctx.accounts.industry_account;
-
-        require!(
-            industry.compliance_status == ComplianceStatus::Compliant,
-            ErrorCode::NotCompliant
+    /// Amend a report; the current version is archived as a revision and must be re-verified
+    pub fn amend_emission_report(
+        ctx: Context<AmendEmissionReport>,
+        co2_emitted: u64,
+    ) -> Result<()> {
+        let report = &mut ctx.accounts.emission_report;
+        let revision = &mut ctx.accounts.revision;
+        let now = Clock::get()?.unix_timestamp;
+
+        revision.report = report.key();
+        revision.version = report.version;
+        revision.co2_emitted = report.co2_emitted;
+        revision.reported_co2 = report.reported_co2;
+        revision.submitted_at = report.submitted_at;
+        revision.verified = report.verified;
+        revision.verified_by = report.verified_by;
+        revision.verified_at = report.verified_at;
+        revision.evidence_hash = report.evidence_hash;
+        revision.superseded_at = now;
+
+        // Recorded obligations keep the last counted amount until the new version is verified
+        report.version += 1;
+        report.co2_emitted = co2_emitted;
+        report.reported_co2 = co2_emitted;
+        report.submitted_at = now;
+        report.verified = false;
+        report.verified_by = Pubkey::default();
+        report.verified_at = 0;
+        report.evidence_hash = [0; 32];
+
+        emit!(EmissionReportAmended {
+            report: report.key(),
+            industry: report.industry,
+            version: report.version,
+            previous_co2: revision.co2_emitted,
+            co2_emitted,
+            timestamp: now,
+        });
+
+        msg!(
+            "Emission report {} amended to v{}: {} tonnes CO2",
+            report.report_period,
+            report.version,
+            co2_emitted
         );
-        require!(industry.bond_amount >= amount, ErrorCode::InsufficientBond);
-
+        Ok(())
+    }
+
+    /// Accredit a third-party auditor (admin)
+    pub fn register_auditor(
+        ctx: Context<RegisterAuditor>,
+        authority: Pubkey,
+        name: String,
+    ) -> Result<()> {
+        let auditor = &mut ctx.accounts.auditor;
+
+        auditor.authority = authority;
+        auditor.name = name;
+        auditor.accredited = true;
+        auditor.accredited_at = Clock::get()?.unix_timestamp;
+        auditor.reports_verified = 0;
+
+        emit!(AuditorAccreditationChanged {
+            auditor: auditor.key(),
+            authority: auditor.authority,
+            accredited: true,
+        });
+
+        msg!("Auditor accredited: {}", auditor.name);
+        Ok(())
+    }
+
+    /// Withdraw an auditor's accreditation (admin)
+    pub fn revoke_auditor(ctx: Context<RevokeAuditor>) -> Result<()> {
+        let auditor = &mut ctx.accounts.auditor;
+
+        auditor.accredited = false;
+
+        emit!(AuditorAccreditationChanged {
+            auditor: auditor.key(),
+            authority: auditor.authority,
+            accredited: false,
+        });
+
+        msg!("Auditor accreditation revoked: {}", auditor.name);
+        Ok(())
+    }
+
+    /// Attest to an emission report, optionally correcting the reported CO2
+    pub fn verify_emission_report(
+        ctx: Context<VerifyEmissionReport>,
+        adjusted_co2: Option<u64>,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        let auditor = &mut ctx.accounts.auditor;
+        let report = &mut ctx.accounts.emission_report;
+
+        require!(!report.verified, ErrorCode::ReportAlreadyVerified);
+        require!(
+            ctx.accounts.auditor_authority.key() != ctx.accounts.industry_account.authority,
+            ErrorCode::SelfVerification
+        );
+
+        if let Some(co2) = adjusted_co2 {
+            report.co2_emitted = co2;
+        }
+        report.verified = true;
+        report.verified_by = auditor.authority;
+        report.verified_at = Clock::get()?.unix_timestamp;
+        report.evidence_hash = evidence_hash;
+
+        auditor.reports_verified += 1;
+
+        emit!(EmissionReportVerified {
+            report: report.key(),
+            industry: report.industry,
+            auditor: auditor.authority,
+            reported_co2: report.reported_co2,
+            co2_emitted: report.co2_emitted,
+            evidence_hash,
+            timestamp: report.verified_at,
+        });
+
+        msg!(
+            "Emission report verified by {}: {} tonnes CO2",
+            auditor.name,
+            report.co2_emitted
+        );
+        Ok(())
+    }
+
+    /// Withdraw bond (only if exiting program)
+    pub fn withdraw_bond(ctx: Context<WithdrawBond>, amount: u64) -> Result<()> {
+        let industry = &mut ctx.accounts.industry_account;
+
+        industry.ensure_in_standing(false)?;
+        require!(
+            industry.compliance_status == ComplianceStatus::Compliant,
+            ErrorCode::NotCompliant
+        );
+        require!(industry.bond_amount >= amount, ErrorCode::InsufficientBond);
+
         // Transfer USDC from vault back to user
         let seeds = &[
-            b"bond_vault_authority",
+            b"bond_vault_authority".as_ref(),
             &[ctx.bumps.vault_authority],
         ];
         let signer = &[&seeds[..]];
@@ -197,9 +1082,255 @@ pub mod carbon_credits {
 
         industry.bond_amount -= amount;
 
+        emit!(BondWithdrawn {
+            industry: industry.key(),
+            amount,
+            bond_amount: industry.bond_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         msg!("Bond withdrawn: {} USDC", amount);
         Ok(())
     }
+
+    /// Slash part of a non-compliant industry's bond into the penalty treasury
+    pub fn slash_bond(ctx: Context<SlashBond>) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let industry = &mut ctx.accounts.industry_account;
+        let now = Clock::get()?.unix_timestamp;
+
+        require!(config.slash_bps > 0, ErrorCode::SlashingDisabled);
+        require!(
+            industry.compliance_status == ComplianceStatus::NonCompliant,
+            ErrorCode::NotSlashable
+        );
+
+        // Grace runs from when the industry fell out of compliance, and again after each slash
+        let grace_start = industry.non_compliant_since.max(industry.last_slashed_at);
+        require!(
+            now >= grace_start
+                .checked_add(config.slash_grace_period)
+                .ok_or(ErrorCode::MathOverflow)?,
+            ErrorCode::GracePeriodActive
+        );
+
+        let amount = (industry.bond_amount as u128 * config.slash_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        require!(amount > 0, ErrorCode::InsufficientBond);
+
+        let seeds = &[
+            b"bond_vault_authority".as_ref(),
+            &[ctx.bumps.vault_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.bond_vault.to_account_info(),
+                    to: ctx.accounts.penalty_treasury.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+
+        industry.bond_amount -= amount;
+        industry.total_slashed += amount;
+        industry.slash_count += 1;
+        industry.last_slashed_at = now;
+
+        emit!(BondSlashed {
+            industry: industry.key(),
+            amount,
+            bond_amount: industry.bond_amount,
+            total_slashed: industry.total_slashed,
+            timestamp: now,
+        });
+
+        msg!(
+            "Bond slashed: {} USDC from {}",
+            amount,
+            industry.company_name
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// USDC cost of `amount` CCT base units at `price` USDC per whole CCT, rounded up
+fn calculate_purchase_cost(amount: u64, price: u64) -> Result<u64> {
+    let cost = (amount as u128)
+        .checked_mul(price as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(CCT_UNIT as u128 - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        / CCT_UNIT as u128;
+
+    u64::try_from(cost).map_err(|_| error!(ErrorCode::MathOverflow))
+}
+
+/// Canonical form of a report period: "YYYY", "YYYY-QN" or "YYYY-MM".
+/// Report PDAs are seeded with this string so clients can derive them directly.
+pub fn normalize_report_period(period: &str) -> Option<String> {
+    let (year, rest) = match period.split_once('-') {
+        Some((year, rest)) => (year, Some(rest)),
+        None => (period, None),
+    };
+
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    match rest {
+        None => Some(year.to_string()),
+        Some(rest) => {
+            if let Some(quarter) = rest.strip_prefix(['Q', 'q']) {
+                match quarter {
+                    "1" | "2" | "3" | "4" => Some(format!("{}-Q{}", year, quarter)),
+                    _ => None,
+                }
+            } else {
+                let month: u8 = rest.parse().ok()?;
+                if !rest.bytes().all(|b| b.is_ascii_digit()) || !(1..=12).contains(&month) {
+                    return None;
+                }
+                Some(format!("{}-{:02}", year, month))
+            }
+        }
+    }
+}
+
+/// Credential NFT name: `{prefix}{company} YYYY-MM-DD`, company trimmed to fit Metaplex's limit
+fn credential_name(prefix: &str, company_name: &str, timestamp: i64) -> String {
+    let date = format_date(timestamp);
+    let budget = mpl_token_metadata::MAX_NAME_LENGTH - prefix.len() - date.len() - 1;
+
+    let mut company = company_name.trim();
+    while company.len() > budget {
+        let mut end = company.len() - 1;
+        while !company.is_char_boundary(end) {
+            end -= 1;
+        }
+        company = company[..end].trim_end();
+    }
+
+    format!("{}{} {}", prefix, company, date)
+}
+
+/// Rewrite a credential's name through the metadata program; `lock` makes it immutable
+fn update_credential_metadata<'info>(
+    token_metadata_program: &Program<'info, Metadata>,
+    metadata: &Account<'info, MetadataAccount>,
+    credential_authority: &UncheckedAccount<'info>,
+    credential_authority_bump: u8,
+    name: String,
+    lock: bool,
+) -> Result<()> {
+    let seeds = &[
+        b"credential_authority".as_ref(),
+        &[credential_authority_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    metadata::update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            token_metadata_program.to_account_info(),
+            metadata::UpdateMetadataAccountsV2 {
+                metadata: metadata.to_account_info(),
+                update_authority: credential_authority.to_account_info(),
+            },
+            signer,
+        ),
+        None,
+        Some(DataV2 {
+            name,
+            symbol: CREDENTIAL_SYMBOL.to_string(),
+            uri: metadata.uri.trim_end_matches('\0').to_string(),
+            seller_fee_basis_points: 0,
+            creators: metadata.creators.clone(),
+            collection: None,
+            uses: None,
+        }),
+        None,
+        if lock { Some(false) } else { None },
+    )
+}
+
+/// Program-signed creator first, so partners can authenticate the credential, then the verifier
+fn credential_creators(credential_authority: Pubkey, verifier: Pubkey) -> Vec<Creator> {
+    vec![
+        Creator {
+            address: credential_authority,
+            verified: true,
+            share: 0,
+        },
+        Creator {
+            address: verifier,
+            verified: false,
+            share: 100,
+        },
+    ]
+}
+
+/// UTC calendar date (YYYY-MM-DD) of a unix timestamp
+fn format_date(timestamp: i64) -> String {
+    // Days-to-civil conversion over 400-year eras (proleptic Gregorian calendar)
+    let days = timestamp.div_euclid(86_400);
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Whether a report period (e.g. "2025-Q3") falls inside a compliance period label (e.g. "2025")
+pub fn period_covers(label: &str, report_period: &str) -> bool {
+    match report_period.strip_prefix(label) {
+        Some(rest) => rest.is_empty() || rest.starts_with('-'),
+        None => false,
+    }
+}
+
+/// Compliance outcome: 1 CCT must be surrendered per reported tonne of CO2.
+/// A shortfall within `warning_bps` of the requirement is a warning; an
+/// industry with no recorded reports is non-compliant.
+pub fn evaluate_obligation(
+    reports_linked: u32,
+    emissions_reported: u64,
+    cct_surrendered: u64,
+    warning_bps: u16,
+) -> Result<ComplianceStatus> {
+    if reports_linked == 0 {
+        return Ok(ComplianceStatus::NonCompliant);
+    }
+
+    let required = (emissions_reported as u128)
+        .checked_mul(CCT_UNIT as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let surrendered = cct_surrendered as u128;
+
+    if surrendered >= required {
+        return Ok(ComplianceStatus::Compliant);
+    }
+
+    let shortfall = required - surrendered;
+    if shortfall * (BPS_DENOMINATOR as u128) <= required * (warning_bps as u128) {
+        Ok(ComplianceStatus::Warning)
+    } else {
+        Ok(ComplianceStatus::NonCompliant)
+    }
 }
 
 // ============================================================================
@@ -214,74 +1345,754 @@ pub struct InitializeMint<'info> {
         mint::decimals = 9,
         mint::authority = mint_authority,
     )]
-    pub cct_mint: Account<'info, Mint>,
+    pub cct_mint: Account<'info, Mint>,
+
+    /// CHECK: PDA authority for minting
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProgramConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"treasury"],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = treasury_authority,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA authority for the USDC treasury
+    #[account(
+        seeds = [b"treasury_authority"],
+        bump,
+    )]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSlashPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(token::mint = config.usdc_mint)]
+    pub penalty_treasury: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterIndustry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + IndustryAccount::INIT_SPACE,
+        seeds = [b"industry", authority.key().as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(address = config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"bond_vault", industry_account.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = vault_authority,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Vault authority PDA
+    #[account(
+        seeds = [b"bond_vault_authority"],
+        bump,
+    )]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = config.usdc_mint,
+        token::authority = authority,
+    )]
+    pub user_usdc: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyIndustry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_verifier(&verifier.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    /// Receives the credential and delegates it to `credential_authority` for revocation
+    #[account(address = industry_account.authority)]
+    pub industry_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = verifier,
+        seeds = [
+            b"credential",
+            industry_account.key().as_ref(),
+            industry_account.credential_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        mint::decimals = 0,
+        mint::authority = credential_authority,
+        mint::freeze_authority = credential_authority,
+    )]
+    pub credential_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = verifier,
+        associated_token::mint = credential_mint,
+        associated_token::authority = industry_authority,
+    )]
+    pub credential_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Credential mint, freeze and metadata update authority PDA
+    #[account(
+        seeds = [b"credential_authority"],
+        bump,
+    )]
+    pub credential_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metadata PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            credential_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition PDA, created by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            credential_mint.key().as_ref(),
+            b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// Admin or a member of the config verifier set; pays for the credential accounts
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetVerificationStatus<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            industry_account.credential_mint.as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    /// CHECK: Credential mint, freeze and metadata update authority PDA
+    #[account(
+        seeds = [b"credential_authority"],
+        bump,
+    )]
+    pub credential_authority: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeIndustry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        address = industry_account.credential_mint,
+    )]
+    pub credential_mint: Account<'info, Mint>,
+
+    /// The holder's credential, burned on revocation
+    #[account(
+        mut,
+        associated_token::mint = credential_mint,
+        associated_token::authority = industry_account.authority,
+    )]
+    pub credential_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            credential_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub metadata: Account<'info, MetadataAccount>,
+
+    /// CHECK: Master edition PDA, the credential's freeze authority
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            credential_mint.key().as_ref(),
+            b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Credential metadata update authority and the holder's delegate
+    #[account(
+        seeds = [b"credential_authority"],
+        bump,
+    )]
+    pub credential_authority: UncheckedAccount<'info>,
+
+    pub admin: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub token_metadata_program: Program<'info, Metadata>,
+}
+
+#[derive(Accounts)]
+pub struct DepositBond<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", authority.key().as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        token::mint = config.usdc_mint,
+        token::authority = authority,
+    )]
+    pub user_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"bond_vault", industry_account.key().as_ref()],
+        bump,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PurchaseCCT<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", authority.key().as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        address = config.cct_mint,
+    )]
+    pub cct_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = config.usdc_mint,
+        token::authority = authority,
+    )]
+    pub user_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = config.treasury,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = cct_mint,
+        associated_token::authority = authority,
+    )]
+    pub industry_cct_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Mint authority PDA
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IssueAuctionCCT<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        address = config.cct_mint,
+    )]
+    pub cct_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = cct_mint,
+        token::authority = industry_account.authority,
+    )]
+    pub industry_cct_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Mint authority PDA
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Issuer PDA of the dutch-auction program
+    #[account(
+        constraint = issuer.key() == config.auction_issuer @ ErrorCode::Unauthorized,
+    )]
+    pub issuer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BurnCCT<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", authority.key().as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    pub compliance_period: Account<'info, CompliancePeriod>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"obligation",
+            compliance_period.key().as_ref(),
+            industry_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub obligation: Account<'info, ComplianceObligation>,
+
+    #[account(
+        mut,
+        address = config.cct_mint,
+    )]
+    pub cct_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = cct_mint,
+        token::authority = authority,
+    )]
+    pub industry_cct_account: Account<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(period_id: u32)]
+pub struct CreateCompliancePeriod<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CompliancePeriod::INIT_SPACE,
+        seeds = [b"compliance_period", period_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub compliance_period: Account<'info, CompliancePeriod>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenComplianceObligation<'info> {
+    pub compliance_period: Account<'info, CompliancePeriod>,
+
+    #[account(
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ComplianceObligation::INIT_SPACE,
+        seeds = [
+            b"obligation",
+            compliance_period.key().as_ref(),
+            industry_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub obligation: Account<'info, ComplianceObligation>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordEmissionReport<'info> {
+    pub compliance_period: Account<'info, CompliancePeriod>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"obligation",
+            compliance_period.key().as_ref(),
+            emission_report.industry.as_ref(),
+        ],
+        bump,
+    )]
+    pub obligation: Account<'info, ComplianceObligation>,
+
+    #[account(mut)]
+    pub emission_report: Account<'info, EmissionReport>,
+}
+
+#[derive(Accounts)]
+pub struct EvaluateCompliance<'info> {
+    pub compliance_period: Account<'info, CompliancePeriod>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"obligation",
+            compliance_period.key().as_ref(),
+            industry_account.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub obligation: Account<'info, ComplianceObligation>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+}
+
+#[derive(Accounts)]
+#[instruction(co2_emitted: u64, report_period: String)]
+pub struct SubmitEmissionReport<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EmissionReport::INIT_SPACE,
+        seeds = [
+            b"emission_report",
+            industry_account.key().as_ref(),
+            report_period.as_bytes(),
+        ],
+        bump,
+    )]
+    pub emission_report: Account<'info, EmissionReport>,
+
+    #[account(
+        seeds = [b"industry", authority.key().as_ref()],
+        bump,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AmendEmissionReport<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"emission_report",
+            industry_account.key().as_ref(),
+            emission_report.report_period.as_bytes(),
+        ],
+        bump,
+    )]
+    pub emission_report: Account<'info, EmissionReport>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EmissionReportRevision::INIT_SPACE,
+        seeds = [
+            b"emission_report_revision",
+            emission_report.key().as_ref(),
+            emission_report.version.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub revision: Account<'info, EmissionReportRevision>,
 
-    /// CHECK: PDA authority for minting
     #[account(
-        seeds = [b"mint_authority"],
+        seeds = [b"industry", authority.key().as_ref()],
         bump,
     )]
-    pub mint_authority: UncheckedAccount<'info>,
+    pub industry_account: Account<'info, IndustryAccount>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct RegisterIndustry<'info> {
+#[instruction(authority: Pubkey)]
+pub struct RegisterAuditor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         init,
-        payer = authority,
-        space = 8 + IndustryAccount::INIT_SPACE,
-        seeds = [b"industry", authority.key().as_ref()],
+        payer = admin,
+        space = 8 + Auditor::INIT_SPACE,
+        seeds = [b"auditor", authority.as_ref()],
         bump,
     )]
-    pub industry_account: Account<'info, IndustryAccount>,
+    pub auditor: Account<'info, Auditor>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub admin: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct VerifyIndustry<'info> {
+pub struct RevokeAuditor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
-        seeds = [b"industry", industry_account.authority.as_ref()],
+        seeds = [b"auditor", auditor.authority.as_ref()],
         bump,
     )]
-    pub industry_account: Account<'info, IndustryAccount>,
+    pub auditor: Account<'info, Auditor>,
 
-    /// CHECK: Admin authority (would be multisig in production)
     pub admin: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct DepositBond<'info> {
+pub struct VerifyEmissionReport<'info> {
     #[account(
         mut,
-        seeds = [b"industry", authority.key().as_ref()],
+        seeds = [b"auditor", auditor_authority.key().as_ref()],
         bump,
+        constraint = auditor.accredited @ ErrorCode::AuditorNotAccredited,
     )]
-    pub industry_account: Account<'info, IndustryAccount>,
+    pub auditor: Account<'info, Auditor>,
 
     #[account(mut)]
-    pub user_usdc: Account<'info, TokenAccount>,
+    pub emission_report: Account<'info, EmissionReport>,
 
-    #[account(mut)]
-    pub bond_vault: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [b"industry", industry_account.authority.as_ref()],
+        bump,
+        constraint = emission_report.industry == industry_account.key()
+            @ ErrorCode::ReportIndustryMismatch,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
 
-    pub authority: Signer<'info>,
-    pub token_program: Program<'info, Token>,
+    pub auditor_authority: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct PurchaseCCT<'info> {
+pub struct WithdrawBond<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
     #[account(
         mut,
         seeds = [b"industry", authority.key().as_ref()],
@@ -289,93 +2100,54 @@ pub struct PurchaseCCT<'info> {
     )]
     pub industry_account: Account<'info, IndustryAccount>,
 
-    #[account(mut)]
-    pub cct_mint: Account<'info, Mint>,
-
     #[account(
-        init_if_needed,
-        payer = authority,
-        associated_token::mint = cct_mint,
-        associated_token::authority = authority,
+        mut,
+        seeds = [b"bond_vault", industry_account.key().as_ref()],
+        bump,
     )]
-    pub industry_cct_account: Account<'info, TokenAccount>,
+    pub bond_vault: Account<'info, TokenAccount>,
 
-    /// CHECK: Mint authority PDA
     #[account(
-        seeds = [b"mint_authority"],
-        bump,
+        mut,
+        token::mint = config.usdc_mint,
+        token::authority = authority,
     )]
-    pub mint_authority: UncheckedAccount<'info>,
-
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
-}
+    pub user_usdc: Account<'info, TokenAccount>,
 
-#[derive(Accounts)]
-pub struct BurnCCT<'info> {
+    /// CHECK: Vault authority PDA
     #[account(
-        mut,
-        seeds = [b"industry", authority.key().as_ref()],
+        seeds = [b"bond_vault_authority"],
         bump,
     )]
-    pub industry_account: Account<'info, IndustryAccount>,
-
-    #[account(mut)]
-    pub cct_mint: Account<'info, Mint>,
-
-    #[account(mut)]
-    pub industry_cct_account: Account<'info, TokenAccount>,
+    pub vault_authority: UncheckedAccount<'info>,
 
     pub authority: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct SubmitEmissionReport<'info> {
+pub struct SlashBond<'info> {
     #[account(
-        init,
-        payer = authority,
-        space = 8 + EmissionReport::INIT_SPACE,
-        seeds = [
-            b"emission_report",
-            industry_account.key().as_ref(),
-            &Clock::get()?.unix_timestamp.to_le_bytes()
-        ],
-        bump,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ ErrorCode::Unauthorized,
     )]
-    pub emission_report: Account<'info, EmissionReport>,
+    pub config: Account<'info, ProgramConfig>,
 
     #[account(
-        seeds = [b"industry", authority.key().as_ref()],
+        mut,
+        seeds = [b"industry", industry_account.authority.as_ref()],
         bump,
     )]
     pub industry_account: Account<'info, IndustryAccount>,
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct WithdrawBond<'info> {
     #[account(
         mut,
-        seeds = [b"industry", authority.key().as_ref()],
+        seeds = [b"bond_vault", industry_account.key().as_ref()],
         bump,
     )]
-    pub industry_account: Account<'info, IndustryAccount>,
-
-    #[account(mut)]
     pub bond_vault: Account<'info, TokenAccount>,
 
-    #[account(mut)]
-    pub user_usdc: Account<'info, TokenAccount>,
-
     /// CHECK: Vault authority PDA
     #[account(
         seeds = [b"bond_vault_authority"],
@@ -383,7 +2155,13 @@ pub struct WithdrawBond<'info> {
     )]
     pub vault_authority: UncheckedAccount<'info>,
 
-    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        address = config.penalty_treasury,
+    )]
+    pub penalty_treasury: Account<'info, TokenAccount>,
+
+    pub admin: Signer<'info>,
     pub token_program: Program<'info, Token>,
 }
 
@@ -391,6 +2169,32 @@ pub struct WithdrawBond<'info> {
 // Accounts
 // ============================================================================
 
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    #[max_len(MAX_VERIFIERS)]
+    pub verifiers: Vec<Pubkey>,
+    pub cct_mint: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub auction_issuer: Pubkey,
+    pub penalty_treasury: Pubkey,
+    pub min_bond: u64,  // USDC (6 decimals)
+    pub cct_price: u64, // USDC (6 decimals) per whole CCT
+    pub slash_bps: u16,
+    pub slash_grace_period: i64, // seconds
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    /// The admin is always allowed to act as a verifier
+    pub fn is_verifier(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.verifiers.contains(key)
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct IndustryAccount {
@@ -398,30 +2202,285 @@ pub struct IndustryAccount {
     #[max_len(100)]
     pub company_name: String,
     pub bond_amount: u64,
-    pub verified: bool,
+    pub verification_status: VerificationStatus,
+    pub status_reason: u16, // Regulator reason code for the last suspension / revocation
+    pub status_updated_at: i64,
     pub cct_balance: u64,
     pub total_purchased: u64,
     pub total_burned: u64,
     pub compliance_status: ComplianceStatus,
     pub created_at: i64,
+    pub non_compliant_since: i64,
+    pub total_slashed: u64,
+    pub slash_count: u32,
+    pub last_slashed_at: i64,
+    pub credential_mint: Pubkey, // Current verification NFT, default when unverified
+    pub credential_count: u32,   // Credentials ever issued, seeds the next credential mint
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+}
+
+impl IndustryAccount {
+    /// Verified industries are in standing; suspended ones only when `allow_suspended`
+    pub fn ensure_in_standing(&self, allow_suspended: bool) -> Result<()> {
+        match self.verification_status {
+            VerificationStatus::Verified => Ok(()),
+            VerificationStatus::Suspended if allow_suspended => Ok(()),
+            VerificationStatus::Suspended => err!(ErrorCode::IndustrySuspended),
+            VerificationStatus::Revoked => err!(ErrorCode::IndustryRevoked),
+            VerificationStatus::Unverified => err!(ErrorCode::NotVerified),
+        }
+    }
+
+    /// Auction CCT settles bids the auction program accepted while the industry was in
+    /// standing, and the issuer already counts that USDC as proceeds, so it is issued whatever
+    /// the industry's status is now. Only accounts that were never verified are refused.
+    pub fn ensure_can_settle_auction(&self) -> Result<()> {
+        require!(
+            self.verification_status != VerificationStatus::Unverified,
+            ErrorCode::NotVerified
+        );
+        Ok(())
+    }
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct EmissionReport {
     pub industry: Pubkey,
+    pub version: u32, // Starts at 1, bumped by each amendment
     pub co2_emitted: u64,
+    pub reported_co2: u64, // As submitted, before any auditor adjustment
     #[max_len(20)]
     pub report_period: String,
     pub submitted_at: i64,
     pub verified: bool,
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+    pub evidence_hash: [u8; 32],
+    pub counted_in: Pubkey, // Obligation this report was recorded against
+    pub counted_co2: u64,
+}
+
+/// Archived copy of a superseded emission report version
+#[account]
+#[derive(InitSpace)]
+pub struct EmissionReportRevision {
+    pub report: Pubkey,
+    pub version: u32,
+    pub co2_emitted: u64,
+    pub reported_co2: u64,
+    pub submitted_at: i64,
+    pub verified: bool,
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+    pub evidence_hash: [u8; 32],
+    pub superseded_at: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Auditor {
+    pub authority: Pubkey,
+    #[max_len(100)]
+    pub name: String,
+    pub accredited: bool,
+    pub accredited_at: i64,
+    pub reports_verified: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CompliancePeriod {
+    pub period_id: u32,
+    #[max_len(20)]
+    pub label: String, // e.g. "2025"; covers reports "2025" and "2025-*"
+    pub start_time: i64,
+    pub deadline: i64,
+    pub warning_bps: u16, // Shortfall tolerated as Warning instead of NonCompliant
+}
+
+impl CompliancePeriod {
+    /// CCT surrendered outside `start_time..=deadline` does not count towards the period
+    pub fn ensure_open(&self, now: i64) -> Result<()> {
+        require!(now >= self.start_time, ErrorCode::PeriodNotStarted);
+        require!(now <= self.deadline, ErrorCode::PeriodClosed);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceObligation {
+    pub industry: Pubkey,
+    pub period: Pubkey,
+    pub emissions_reported: u64, // Tonnes CO2 from recorded, verified reports
+    pub reports_linked: u32,
+    pub cct_surrendered: u64, // CCT base units burned in the period
+    pub status: ComplianceStatus,
+    pub evaluated_at: i64,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct IndustryRegistered {
+    pub industry: Pubkey,
+    pub authority: Pubkey,
+    pub company_name: String,
+    pub bond_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct IndustryVerified {
+    pub industry: Pubkey,
+    pub verifier: Pubkey,
+    pub credential_mint: Pubkey,
+    pub credential_count: u32,
+    pub timestamp: i64,
+}
+
+/// Suspension, reinstatement or revocation of an industry
+#[event]
+pub struct IndustryStatusChanged {
+    pub industry: Pubkey,
+    pub status: VerificationStatus,
+    pub reason_code: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BondDeposited {
+    pub industry: Pubkey,
+    pub amount: u64,
+    pub bond_amount: u64, // Bond held after the deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CctPurchased {
+    pub industry: Pubkey,
+    pub amount: u64,
+    pub price: u64,
+    pub cost: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionCctIssued {
+    pub industry: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CctBurned {
+    pub industry: Pubkey,
+    pub period: Pubkey,
+    pub obligation: Pubkey,
+    pub amount: u64,
+    pub cct_surrendered: u64, // Obligation total after the burn
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompliancePeriodCreated {
+    pub period: Pubkey,
+    pub period_id: u32,
+    pub label: String,
+    pub start_time: i64,
+    pub deadline: i64,
+    pub warning_bps: u16,
+}
+
+#[event]
+pub struct ComplianceObligationOpened {
+    pub obligation: Pubkey,
+    pub industry: Pubkey,
+    pub period: Pubkey,
+}
+
+#[event]
+pub struct EmissionReportRecorded {
+    pub report: Pubkey,
+    pub obligation: Pubkey,
+    pub co2_emitted: u64,
+    pub emissions_reported: u64, // Obligation total after recording
+}
+
+#[event]
+pub struct ComplianceEvaluated {
+    pub industry: Pubkey,
+    pub period: Pubkey,
+    pub obligation: Pubkey,
+    pub status: ComplianceStatus,
+    pub emissions_reported: u64,
+    pub cct_surrendered: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionReportSubmitted {
+    pub report: Pubkey,
+    pub industry: Pubkey,
+    pub report_period: String,
+    pub co2_emitted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionReportAmended {
+    pub report: Pubkey,
+    pub industry: Pubkey,
+    pub version: u32,
+    pub previous_co2: u64,
+    pub co2_emitted: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmissionReportVerified {
+    pub report: Pubkey,
+    pub industry: Pubkey,
+    pub auditor: Pubkey,
+    pub reported_co2: u64,
+    pub co2_emitted: u64, // After any auditor adjustment
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuditorAccreditationChanged {
+    pub auditor: Pubkey,
+    pub authority: Pubkey,
+    pub accredited: bool,
+}
+
+#[event]
+pub struct BondWithdrawn {
+    pub industry: Pubkey,
+    pub amount: u64,
+    pub bond_amount: u64, // Bond held after the withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BondSlashed {
+    pub industry: Pubkey,
+    pub amount: u64,
+    pub bond_amount: u64,
+    pub total_slashed: u64,
+    pub timestamp: i64,
 }
 
 // ============================================================================
 // Enums
 // ============================================================================
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum ComplianceStatus {
     Pending,
     Compliant,
@@ -429,13 +2488,21 @@ pub enum ComplianceStatus {
     Warning,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum VerificationStatus {
+    Unverified,
+    Verified,
+    Suspended,
+    Revoked,
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
 
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Insufficient bond amount. Minimum 1000 USDC required.")]
+    #[msg("Insufficient bond amount. Below the configured minimum.")]
     InsufficientBond,
 
     #[msg("Industry is not verified yet.")]
@@ -449,4 +2516,100 @@ pub enum ErrorCode {
 
     #[msg("Industry is not compliant. Cannot withdraw bond.")]
     NotCompliant,
+
+    #[msg("CCT price must be greater than 0.")]
+    InvalidPrice,
+
+    #[msg("Amount must be greater than 0.")]
+    InvalidAmount,
+
+    #[msg("CCT price exceeds the maximum price accepted by the buyer.")]
+    SlippageExceeded,
+
+    #[msg("Math overflow occurred.")]
+    MathOverflow,
+
+    #[msg("Signer is not authorized for this operation.")]
+    Unauthorized,
+
+    #[msg("Signer is not the pending admin.")]
+    NotPendingAdmin,
+
+    #[msg("Verifier is already registered.")]
+    VerifierAlreadyExists,
+
+    #[msg("Verifier is not registered.")]
+    VerifierNotFound,
+
+    #[msg("Verifier set is full.")]
+    TooManyVerifiers,
+
+    #[msg("Slash share cannot exceed 10000 bps.")]
+    InvalidSlashBps,
+
+    #[msg("Grace period cannot be negative.")]
+    InvalidGracePeriod,
+
+    #[msg("Bond slashing is disabled.")]
+    SlashingDisabled,
+
+    #[msg("Only non-compliant industries can be slashed.")]
+    NotSlashable,
+
+    #[msg("Slash grace period has not elapsed.")]
+    GracePeriodActive,
+
+    #[msg("Compliance period deadline must be after its start.")]
+    InvalidPeriod,
+
+    #[msg("Warning threshold cannot exceed 10000 bps.")]
+    InvalidWarningBps,
+
+    #[msg("Compliance period deadline has passed.")]
+    PeriodClosed,
+
+    #[msg("Compliance period deadline has not passed yet.")]
+    PeriodNotEnded,
+
+    #[msg("Obligation has already been evaluated.")]
+    AlreadyEvaluated,
+
+    #[msg("Emission report has not been verified.")]
+    ReportNotVerified,
+
+    #[msg("Emission report does not belong to this compliance period.")]
+    ReportPeriodMismatch,
+
+    #[msg("Emission report is already counted towards another obligation.")]
+    ReportCountedElsewhere,
+
+    #[msg("Auditor is not accredited.")]
+    AuditorNotAccredited,
+
+    #[msg("Emission report has already been verified.")]
+    ReportAlreadyVerified,
+
+    #[msg("Auditor cannot verify their own industry's report.")]
+    SelfVerification,
+
+    #[msg("Emission report does not belong to this industry.")]
+    ReportIndustryMismatch,
+
+    #[msg("Report period must be canonical: YYYY, YYYY-QN or YYYY-MM.")]
+    InvalidReportPeriod,
+
+    #[msg("Credential metadata URI is too long.")]
+    InvalidMetadataUri,
+
+    #[msg("Industry is suspended.")]
+    IndustrySuspended,
+
+    #[msg("Industry verification has been revoked.")]
+    IndustryRevoked,
+
+    #[msg("Industry is not suspended.")]
+    NotSuspended,
+
+    #[msg("Compliance period has not started yet.")]
+    PeriodNotStarted,
 }
@@ -0,0 +1,251 @@
+//! Account validation for compliance burns. Each test feeds `BurnCCT::try_accounts` a
+//! canonical account set with one account swapped for a spoofed one and checks the matching
+//! Anchor constraint rejects it.
+
+use std::collections::BTreeSet;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_program;
+use anchor_spl::token::spl_token;
+use carbon_credits::{
+    BurnCCT, BurnCCTBumps, ComplianceObligation, CompliancePeriod, ComplianceStatus,
+    IndustryAccount, ProgramConfig, VerificationStatus,
+};
+
+const NOW: i64 = 1_700_000_000;
+
+struct TestAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 1_000_000_000,
+            data,
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    fn signer(key: Pubkey) -> Self {
+        Self {
+            is_signer: true,
+            ..Self::new(key, system_program::ID, vec![])
+        }
+    }
+
+    fn program(key: Pubkey) -> Self {
+        Self {
+            is_writable: false,
+            executable: true,
+            ..Self::new(key, Pubkey::default(), vec![])
+        }
+    }
+
+    fn anchor<T: AccountSerialize>(key: Pubkey, state: &T) -> Self {
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        Self::new(key, carbon_credits::ID, data)
+    }
+
+    fn token(key: Pubkey, mint: Pubkey, owner: Pubkey) -> Self {
+        let state = spl_token::state::Account {
+            mint,
+            owner,
+            amount: 1_000_000_000,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        state.pack_into_slice(&mut data);
+        Self::new(key, spl_token::ID, data)
+    }
+
+    fn mint(key: Pubkey) -> Self {
+        let state = spl_token::state::Mint {
+            decimals: 9,
+            is_initialized: true,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        state.pack_into_slice(&mut data);
+        Self::new(key, spl_token::ID, data)
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &carbon_credits::ID)
+}
+
+/// Keys shared by every fixture
+struct World {
+    authority: Pubkey,
+    cct_mint: Pubkey,
+}
+
+impl World {
+    fn new() -> Self {
+        Self {
+            authority: Pubkey::new_unique(),
+            cct_mint: Pubkey::new_unique(),
+        }
+    }
+
+    fn burn_accounts(&self) -> Vec<TestAccount> {
+        let (config, config_bump) = pda(&[b"config"]);
+        let (industry, _) = pda(&[b"industry", self.authority.as_ref()]);
+        let period = Pubkey::new_unique();
+        let (obligation, _) = pda(&[b"obligation", period.as_ref(), industry.as_ref()]);
+
+        let program_config = ProgramConfig {
+            admin: Pubkey::new_unique(),
+            pending_admin: None,
+            verifiers: vec![],
+            cct_mint: self.cct_mint,
+            usdc_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            auction_issuer: Pubkey::new_unique(),
+            penalty_treasury: Pubkey::new_unique(),
+            min_bond: 0,
+            cct_price: 1,
+            slash_bps: 0,
+            slash_grace_period: 0,
+            bump: config_bump,
+        };
+        let industry_account = IndustryAccount {
+            authority: self.authority,
+            company_name: "Acme".to_string(),
+            bond_amount: 0,
+            verification_status: VerificationStatus::Verified,
+            status_reason: 0,
+            status_updated_at: NOW,
+            cct_balance: 1_000_000_000,
+            total_purchased: 1_000_000_000,
+            total_burned: 0,
+            compliance_status: ComplianceStatus::Compliant,
+            created_at: NOW,
+            non_compliant_since: 0,
+            total_slashed: 0,
+            slash_count: 0,
+            last_slashed_at: 0,
+            credential_mint: Pubkey::new_unique(),
+            credential_count: 1,
+            verified_by: Pubkey::new_unique(),
+            verified_at: NOW,
+        };
+        let compliance_period = CompliancePeriod {
+            period_id: 1,
+            label: "2025".to_string(),
+            start_time: NOW - 100,
+            deadline: NOW + 100,
+            warning_bps: 0,
+        };
+        let compliance_obligation = ComplianceObligation {
+            industry,
+            period,
+            emissions_reported: 1,
+            reports_linked: 1,
+            cct_surrendered: 0,
+            status: ComplianceStatus::Compliant,
+            evaluated_at: 0,
+        };
+
+        vec![
+            TestAccount::anchor(config, &program_config),
+            TestAccount::anchor(industry, &industry_account),
+            TestAccount::anchor(period, &compliance_period),
+            TestAccount::anchor(obligation, &compliance_obligation),
+            TestAccount::mint(self.cct_mint),
+            TestAccount::token(Pubkey::new_unique(), self.cct_mint, self.authority),
+            TestAccount::signer(self.authority),
+            TestAccount::program(spl_token::ID),
+        ]
+    }
+}
+
+fn burn(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    BurnCCT::try_accounts(
+        &carbon_credits::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut BurnCCTBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn assert_rejected(result: Result<()>, expected: AnchorErrorCode) {
+    match result {
+        Err(Error::AnchorError(err)) => assert_eq!(
+            err.error_code_number,
+            u32::from(expected),
+            "unexpected error: {}",
+            err
+        ),
+        Err(err) => panic!("expected {:?}, got {:?}", expected, err),
+        Ok(()) => panic!("expected {:?}, accounts were accepted", expected),
+    }
+}
+
+// burn_cct_for_compliance: accounts [config, industry, period, obligation, mint, cct_account, ..]
+
+#[test]
+fn burn_accepts_real_cct() {
+    let world = World::new();
+    burn(&mut world.burn_accounts()).unwrap();
+}
+
+#[test]
+fn burn_rejects_a_self_made_mint() {
+    let world = World::new();
+    let mut accounts = world.burn_accounts();
+    let fake_mint = Pubkey::new_unique();
+    accounts[4] = TestAccount::mint(fake_mint);
+    accounts[5] = TestAccount::token(Pubkey::new_unique(), fake_mint, world.authority);
+
+    assert_rejected(burn(&mut accounts), AnchorErrorCode::ConstraintAddress);
+}
+
+#[test]
+fn burn_rejects_tokens_of_another_mint() {
+    let world = World::new();
+    let mut accounts = world.burn_accounts();
+    accounts[5] = TestAccount::token(Pubkey::new_unique(), Pubkey::new_unique(), world.authority);
+
+    assert_rejected(burn(&mut accounts), AnchorErrorCode::ConstraintTokenMint);
+}
+
+#[test]
+fn burn_rejects_someone_elses_token_account() {
+    let world = World::new();
+    let mut accounts = world.burn_accounts();
+    accounts[5] = TestAccount::token(Pubkey::new_unique(), world.cct_mint, Pubkey::new_unique());
+
+    assert_rejected(burn(&mut accounts), AnchorErrorCode::ConstraintTokenOwner);
+}
@@ -0,0 +1,80 @@
+use anchor_lang::error::Error;
+use carbon_credits::{
+    evaluate_obligation, normalize_report_period, period_covers, CompliancePeriod,
+    ComplianceStatus, ErrorCode, CCT_UNIT,
+};
+
+fn status(reports: u32, tonnes: u64, surrendered: u64, warning_bps: u16) -> ComplianceStatus {
+    evaluate_obligation(reports, tonnes, surrendered, warning_bps).unwrap()
+}
+
+#[test]
+fn report_periods_normalize_to_canonical_labels() {
+    assert_eq!(normalize_report_period("2025").as_deref(), Some("2025"));
+    assert_eq!(normalize_report_period("2025-Q3").as_deref(), Some("2025-Q3"));
+    assert_eq!(normalize_report_period("2025-q3").as_deref(), Some("2025-Q3"));
+    assert_eq!(normalize_report_period("2025-3").as_deref(), Some("2025-03"));
+    assert_eq!(normalize_report_period("2025-12").as_deref(), Some("2025-12"));
+}
+
+#[test]
+fn malformed_report_periods_are_rejected() {
+    for period in [
+        "", "25", "20x5", "2025-", "2025-Q0", "2025-Q5", "2025-00", "2025-13", "2025-+3",
+        " 2025", "2025 ",
+    ] {
+        assert_eq!(normalize_report_period(period), None, "{:?}", period);
+    }
+}
+
+#[test]
+fn compliance_period_covers_its_quarters_and_months() {
+    assert!(period_covers("2025", "2025"));
+    assert!(period_covers("2025", "2025-Q3"));
+    assert!(period_covers("2025", "2025-03"));
+    assert!(period_covers("2025-Q3", "2025-Q3"));
+
+    assert!(!period_covers("2025", "2024-Q4"));
+    assert!(!period_covers("2025", "2026"));
+    assert!(!period_covers("2025-Q3", "2025"));
+    assert!(!period_covers("202", "2025"));
+}
+
+#[test]
+fn obligation_is_met_once_every_tonne_is_surrendered() {
+    assert_eq!(status(1, 100, 100 * CCT_UNIT, 0), ComplianceStatus::Compliant);
+    // Over-surrendering is still compliant
+    assert_eq!(status(2, 100, 150 * CCT_UNIT, 0), ComplianceStatus::Compliant);
+}
+
+#[test]
+fn shortfall_within_tolerance_is_a_warning() {
+    // 10% tolerance on 100 tonnes
+    assert_eq!(status(1, 100, 95 * CCT_UNIT, 1_000), ComplianceStatus::Warning);
+    assert_eq!(status(1, 100, 90 * CCT_UNIT, 1_000), ComplianceStatus::Warning);
+    assert_eq!(status(1, 100, 90 * CCT_UNIT - 1, 1_000), ComplianceStatus::NonCompliant);
+    // Without tolerance a single base unit short fails
+    assert_eq!(status(1, 100, 100 * CCT_UNIT - 1, 0), ComplianceStatus::NonCompliant);
+}
+
+#[test]
+fn obligation_without_reports_is_non_compliant() {
+    assert_eq!(status(0, 0, 0, 1_000), ComplianceStatus::NonCompliant);
+    assert_eq!(status(0, 0, 100 * CCT_UNIT, 1_000), ComplianceStatus::NonCompliant);
+}
+
+#[test]
+fn surrenders_only_count_while_the_period_is_open() {
+    let period = CompliancePeriod {
+        period_id: 1,
+        label: "2025".to_string(),
+        start_time: 1_000,
+        deadline: 2_000,
+        warning_bps: 0,
+    };
+
+    period.ensure_open(1_000).unwrap();
+    period.ensure_open(2_000).unwrap();
+    assert_eq!(period.ensure_open(999).unwrap_err(), Error::from(ErrorCode::PeriodNotStarted));
+    assert_eq!(period.ensure_open(2_001).unwrap_err(), Error::from(ErrorCode::PeriodClosed));
+}
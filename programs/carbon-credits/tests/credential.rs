@@ -0,0 +1,335 @@
+//! Credential revocation. Runs `revoke_industry` through the program entrypoint with the SPL
+//! Token processor behind its CPIs, then checks what is left in the holder's wallet.
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::system_program;
+use anchor_lang::InstructionData;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::metadata::mpl_token_metadata::accounts::Metadata;
+use anchor_spl::metadata::mpl_token_metadata::types::{Creator, Key};
+use anchor_spl::token::spl_token;
+use anchor_spl::token::spl_token::processor::Processor;
+use anchor_spl::token::spl_token::state::{Account as TokenState, AccountState, Mint as MintState};
+use carbon_credits::{ComplianceStatus, IndustryAccount, ProgramConfig, VerificationStatus};
+
+const NOW: i64 = 1_700_000_000;
+
+/// Token Metadata's `ThawDelegatedAccount` instruction
+const THAW_DELEGATED_ACCOUNT: u8 = 27;
+
+/// Host stand-ins for the clock and the token programs. SPL Token CPIs run the real processor;
+/// Token Metadata's delegated thaw is replayed as the edition-signed thaw it performs on chain,
+/// and its metadata updates are ignored.
+struct TestStubs;
+
+impl SyscallStubs for TestStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        // Order the accounts as the instruction lists them, with its PDA signatures applied
+        let accounts: Vec<AccountInfo> = instruction
+            .accounts
+            .iter()
+            .map(|meta| {
+                let mut info = account_infos
+                    .iter()
+                    .find(|info| *info.key == meta.pubkey)
+                    .unwrap()
+                    .clone();
+                info.is_signer |= meta.is_signer;
+                info
+            })
+            .collect();
+
+        if instruction.program_id == spl_token::ID {
+            return Processor::process(&spl_token::ID, &accounts, &instruction.data);
+        }
+        if instruction.program_id == anchor_spl::metadata::ID
+            && instruction.data[0] == THAW_DELEGATED_ACCOUNT
+        {
+            // [delegate, token_account, edition, mint, token_program]
+            let [delegate, token_account, edition, mint, _] = &accounts[..] else {
+                panic!("unexpected ThawDelegatedAccount accounts");
+            };
+            let state = TokenState::unpack(&token_account.data.borrow())?;
+            assert!(delegate.is_signer);
+            assert_eq!(state.delegate, COption::Some(*delegate.key));
+
+            let mut edition = edition.clone();
+            edition.is_signer = true;
+            let thaw = spl_token::instruction::thaw_account(
+                &spl_token::ID,
+                token_account.key,
+                mint.key,
+                edition.key,
+                &[],
+            )?;
+            return Processor::process(
+                &spl_token::ID,
+                &[token_account.clone(), mint.clone(), edition],
+                &thaw.data,
+            );
+        }
+        Ok(())
+    }
+}
+
+struct TestAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 1_000_000_000,
+            data,
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    fn signer(key: Pubkey) -> Self {
+        Self {
+            is_signer: true,
+            ..Self::new(key, system_program::ID, vec![])
+        }
+    }
+
+    fn program(key: Pubkey) -> Self {
+        Self {
+            is_writable: false,
+            executable: true,
+            ..Self::new(key, Pubkey::default(), vec![])
+        }
+    }
+
+    fn anchor<T: AccountSerialize>(key: Pubkey, state: &T) -> Self {
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        Self::new(key, carbon_credits::ID, data)
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+fn pda(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+/// A verified industry whose credential sits frozen in its wallet, delegated to the program
+struct World {
+    admin: Pubkey,
+    authority: Pubkey,
+    credential_mint: Pubkey,
+    credential_authority: Pubkey,
+    master_edition: Pubkey,
+}
+
+impl World {
+    fn new() -> Self {
+        let credential_mint = Pubkey::new_unique();
+        let metadata_program = anchor_spl::metadata::ID;
+        Self {
+            admin: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            credential_mint,
+            credential_authority: pda(&[b"credential_authority"], &carbon_credits::ID).0,
+            master_edition: pda(
+                &[b"metadata", metadata_program.as_ref(), credential_mint.as_ref(), b"edition"],
+                &metadata_program,
+            )
+            .0,
+        }
+    }
+
+    fn credential_account(&self, holder: &Pubkey) -> TestAccount {
+        let key = get_associated_token_address(holder, &self.credential_mint);
+        let state = TokenState {
+            mint: self.credential_mint,
+            owner: *holder,
+            amount: 1,
+            delegate: COption::Some(self.credential_authority),
+            delegated_amount: 1,
+            state: AccountState::Frozen,
+            ..Default::default()
+        };
+        let mut data = vec![0; TokenState::LEN];
+        state.pack_into_slice(&mut data);
+        TestAccount::new(key, spl_token::ID, data)
+    }
+
+    /// Accounts in `RevokeIndustry` order
+    fn revoke_accounts(&self) -> Vec<TestAccount> {
+        let (config, config_bump) = pda(&[b"config"], &carbon_credits::ID);
+        let industry = pda(&[b"industry", self.authority.as_ref()], &carbon_credits::ID).0;
+        let metadata_program = anchor_spl::metadata::ID;
+        let metadata = pda(
+            &[b"metadata", metadata_program.as_ref(), self.credential_mint.as_ref()],
+            &metadata_program,
+        )
+        .0;
+
+        let program_config = ProgramConfig {
+            admin: self.admin,
+            pending_admin: None,
+            verifiers: vec![],
+            cct_mint: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            auction_issuer: Pubkey::new_unique(),
+            penalty_treasury: Pubkey::new_unique(),
+            min_bond: 0,
+            cct_price: 1,
+            slash_bps: 0,
+            slash_grace_period: 0,
+            bump: config_bump,
+        };
+        let industry_account = IndustryAccount {
+            authority: self.authority,
+            company_name: "Acme".to_string(),
+            bond_amount: 0,
+            verification_status: VerificationStatus::Verified,
+            status_reason: 0,
+            status_updated_at: 0,
+            cct_balance: 0,
+            total_purchased: 0,
+            total_burned: 0,
+            compliance_status: ComplianceStatus::Compliant,
+            created_at: 0,
+            non_compliant_since: 0,
+            total_slashed: 0,
+            slash_count: 0,
+            last_slashed_at: 0,
+            credential_mint: self.credential_mint,
+            credential_count: 1,
+            verified_by: self.admin,
+            verified_at: 0,
+        };
+
+        let mint_state = MintState {
+            mint_authority: COption::Some(self.master_edition),
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: COption::Some(self.master_edition),
+        };
+        let mut mint_data = vec![0; MintState::LEN];
+        mint_state.pack_into_slice(&mut mint_data);
+
+        let metadata_state = Metadata {
+            key: Key::MetadataV1,
+            update_authority: self.credential_authority,
+            mint: self.credential_mint,
+            name: "Acme".to_string(),
+            symbol: "CCTV".to_string(),
+            uri: "https://example.com/acme.json".to_string(),
+            seller_fee_basis_points: 0,
+            creators: Some(vec![Creator {
+                address: self.credential_authority,
+                verified: true,
+                share: 0,
+            }]),
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        };
+
+        vec![
+            TestAccount::anchor(config, &program_config),
+            TestAccount::anchor(industry, &industry_account),
+            TestAccount::new(self.credential_mint, spl_token::ID, mint_data),
+            self.credential_account(&self.authority),
+            TestAccount::new(metadata, metadata_program, metadata_state.try_to_vec().unwrap()),
+            TestAccount::new(self.master_edition, metadata_program, vec![]),
+            TestAccount::new(self.credential_authority, system_program::ID, vec![]),
+            TestAccount::signer(self.admin),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(metadata_program),
+        ]
+    }
+}
+
+fn revoke(accounts: &mut [TestAccount]) -> ProgramResult {
+    set_syscall_stubs(Box::new(TestStubs));
+    let data = carbon_credits::instruction::RevokeIndustry { reason_code: 7 }.data();
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    carbon_credits::entry(&carbon_credits::ID, &infos, &data)
+}
+
+#[test]
+fn revocation_burns_the_credential_out_of_the_holders_wallet() {
+    let world = World::new();
+    let mut accounts = world.revoke_accounts();
+
+    revoke(&mut accounts).unwrap();
+
+    let credential = TokenState::unpack(&accounts[3].data).unwrap();
+    assert_eq!(credential.amount, 0);
+    assert_eq!(credential.delegate, COption::None);
+    // Thawed and empty, so the holder can close it and reclaim the rent
+    assert_eq!(credential.state, AccountState::Initialized);
+    assert_eq!(MintState::unpack(&accounts[2].data).unwrap().supply, 0);
+
+    let industry = IndustryAccount::try_deserialize(&mut &accounts[1].data[..]).unwrap();
+    assert_eq!(industry.verification_status, VerificationStatus::Revoked);
+    assert_eq!(industry.status_reason, 7);
+    assert_eq!(industry.credential_mint, Pubkey::default());
+}
+
+#[test]
+fn revocation_only_burns_from_the_holders_associated_account() {
+    let world = World::new();
+    let mut accounts = world.revoke_accounts();
+    // Same holder and mint, but not its associated token account
+    accounts[3].key = Pubkey::new_unique();
+
+    assert_eq!(
+        revoke(&mut accounts).unwrap_err(),
+        ProgramError::Custom(AnchorErrorCode::ConstraintAssociated.into())
+    );
+    // Nothing was burned
+    assert_eq!(MintState::unpack(&accounts[2].data).unwrap().supply, 1);
+}
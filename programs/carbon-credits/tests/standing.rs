@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use carbon_credits::{ComplianceStatus, ErrorCode, IndustryAccount, VerificationStatus};
+
+fn industry(verification_status: VerificationStatus) -> IndustryAccount {
+    IndustryAccount {
+        authority: Pubkey::new_unique(),
+        company_name: "Acme".to_string(),
+        bond_amount: 0,
+        verification_status,
+        status_reason: 0,
+        status_updated_at: 0,
+        cct_balance: 0,
+        total_purchased: 0,
+        total_burned: 0,
+        compliance_status: ComplianceStatus::Compliant,
+        created_at: 0,
+        non_compliant_since: 0,
+        total_slashed: 0,
+        slash_count: 0,
+        last_slashed_at: 0,
+        credential_mint: Pubkey::new_unique(),
+        credential_count: 1,
+        verified_by: Pubkey::new_unique(),
+        verified_at: 0,
+    }
+}
+
+fn assert_error(result: Result<()>, expected: ErrorCode) {
+    assert_eq!(result.unwrap_err(), Error::from(expected));
+}
+
+#[test]
+fn only_verified_industries_are_in_full_standing() {
+    industry(VerificationStatus::Verified).ensure_in_standing(false).unwrap();
+
+    assert_error(
+        industry(VerificationStatus::Suspended).ensure_in_standing(false),
+        ErrorCode::IndustrySuspended,
+    );
+    assert_error(
+        industry(VerificationStatus::Revoked).ensure_in_standing(false),
+        ErrorCode::IndustryRevoked,
+    );
+    assert_error(
+        industry(VerificationStatus::Unverified).ensure_in_standing(false),
+        ErrorCode::NotVerified,
+    );
+}
+
+#[test]
+fn suspended_industries_keep_compliance_standing() {
+    industry(VerificationStatus::Suspended).ensure_in_standing(true).unwrap();
+
+    assert_error(
+        industry(VerificationStatus::Revoked).ensure_in_standing(true),
+        ErrorCode::IndustryRevoked,
+    );
+}
+
+#[test]
+fn winners_out_of_standing_still_settle_their_auction_bids() {
+    // Standing was checked when the bid was placed; the issuer already holds the USDC
+    for status in [
+        VerificationStatus::Verified,
+        VerificationStatus::Suspended,
+        VerificationStatus::Revoked,
+    ] {
+        industry(status).ensure_can_settle_auction().unwrap();
+    }
+
+    assert_error(
+        industry(VerificationStatus::Unverified).ensure_can_settle_auction(),
+        ErrorCode::NotVerified,
+    );
+}
@@ -1,70 +1,178 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use carbon_credits::program::CarbonCredits;
+use carbon_credits::{IndustryAccount, ProgramConfig};
 
 declare_id!("AUCTxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx");
 
+/// Maximum number of breakpoints in a piecewise-linear price curve
+pub const MAX_PRICE_BREAKPOINTS: usize = auction_pricing::MAX_BREAKPOINTS;
+
+/// Maximum number of distinct revealed prices in a sealed-bid auction
+pub const MAX_SEALED_PRICE_LEVELS: usize = 64;
+
+// Auction prices are per whole CCT, quoted like the carbon-credits `cct_price`
+const _: () = assert!(auction_pricing::CCT_UNIT == carbon_credits::CCT_UNIT);
+
 #[program]
 pub mod dutch_auction {
     use super::*;
 
-    /// Initialize a new Dutch auction
+    /// Initialize a new Dutch auction. A `start_time` in the future announces the batch as
+    /// `Scheduled`; it opens by itself once the clock passes `start_time`.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_auction(
         ctx: Context<CreateAuction>,
         batch_number: u32,
         total_tokens: u64,
-        start_price: u64,      // In lamports (USDC)
+        start_price: u64,      // USDC base units per whole CCT
         reserve_price: u64,     // Minimum price
+        start_time: i64,        // Opening time, now or earlier to open immediately
         duration_seconds: i64,  // Auction duration
+        price_curve: PriceCurve,
+        bid_limits: BidLimits,
+        extension: ExtensionPolicy,
+        min_raise: u64,          // USDC below which the auction fails, 0 for none
     ) -> Result<()> {
         let auction = &mut ctx.accounts.auction;
         let clock = Clock::get()?;
 
-        require!(start_price > reserve_price, ErrorCode::InvalidPricing);
-        require!(duration_seconds > 0, ErrorCode::InvalidDuration);
+        configure_auction(
+            auction,
+            clock.unix_timestamp,
+            total_tokens,
+            start_price,
+            reserve_price,
+            start_time,
+            duration_seconds,
+            price_curve,
+            bid_limits,
+            extension,
+            min_raise,
+        )?;
 
         auction.authority = ctx.accounts.authority.key();
         auction.batch_number = batch_number;
-        auction.total_tokens = total_tokens;
-        auction.tokens_remaining = total_tokens;
-        auction.start_price = start_price;
-        auction.current_price = start_price;
-        auction.reserve_price = reserve_price;
-        auction.start_time = clock.unix_timestamp;
-        auction.end_time = clock.unix_timestamp + duration_seconds;
-        auction.status = AuctionStatus::Active;
+        auction.series = Pubkey::default();
+        auction.kind = AuctionKind::Dutch;
         auction.total_raised = 0;
         auction.participant_count = 0;
+        auction.usdc_mint = ctx.accounts.usdc_mint.key();
+        auction.treasury = ctx.accounts.treasury.key();
+        auction.proceeds_withdrawn = 0;
+        auction.clearing_price = 0;
+        auction.unsold_tokens = 0;
+
+        emit!(AuctionCreated {
+            auction: auction.key(),
+            authority: auction.authority,
+            batch_number,
+            total_tokens,
+            start_price,
+            reserve_price,
+            price_curve,
+            bid_limits,
+            kind: auction.kind.clone(),
+            extension,
+            min_raise,
+            start_time: auction.start_time,
+            end_time: auction.end_time,
+        });
 
         msg!("Dutch auction #{} created: {} CCT tokens", batch_number, total_tokens);
         Ok(())
     }
 
-    /// Place a bid in the auction
+    /// Replace the parameters of a scheduled auction before it opens (admin only). Series
+    /// batches are sized against the series budget and template, so they cannot be updated.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_auction(
+        ctx: Context<UpdateAuction>,
+        total_tokens: u64,
+        start_price: u64,
+        reserve_price: u64,
+        start_time: i64,
+        duration_seconds: i64,
+        price_curve: PriceCurve,
+        bid_limits: BidLimits,
+        extension: ExtensionPolicy,
+        min_raise: u64,
+    ) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let clock = Clock::get()?;
+
+        require!(
+            auction.status == AuctionStatus::Scheduled && clock.unix_timestamp < auction.start_time,
+            ErrorCode::AuctionAlreadyStarted
+        );
+
+        configure_auction(
+            auction,
+            clock.unix_timestamp,
+            total_tokens,
+            start_price,
+            reserve_price,
+            start_time,
+            duration_seconds,
+            price_curve,
+            bid_limits,
+            extension,
+            min_raise,
+        )?;
+
+        emit!(AuctionUpdated {
+            auction: auction.key(),
+            batch_number: auction.batch_number,
+            total_tokens,
+            start_price,
+            reserve_price,
+            price_curve,
+            bid_limits,
+            extension,
+            min_raise,
+            start_time: auction.start_time,
+            end_time: auction.end_time,
+        });
+
+        msg!("Auction #{} updated, opens at {}", auction.batch_number, auction.start_time);
+        Ok(())
+    }
+
+    /// Place a bid in the auction, failing if the price has risen above `max_price_per_token`
+    /// by the time the transaction lands
     pub fn place_bid(
         ctx: Context<PlaceBid>,
         token_amount: u64,
+        max_price_per_token: u64,
     ) -> Result<()> {
         let auction = &mut ctx.accounts.auction;
         let bid = &mut ctx.accounts.bid;
         let clock = Clock::get()?;
 
+        require!(auction.kind == AuctionKind::Dutch, ErrorCode::WrongAuctionKind);
+        ctx.accounts.industry_account.ensure_in_standing(false)?;
+        activate_if_started(auction, clock.unix_timestamp);
+        require!(auction.status != AuctionStatus::Scheduled, ErrorCode::AuctionNotStarted);
         require!(auction.status == AuctionStatus::Active, ErrorCode::AuctionNotActive);
         require!(clock.unix_timestamp < auction.end_time, ErrorCode::AuctionEnded);
         require!(token_amount <= auction.tokens_remaining, ErrorCode::InsufficientTokens);
 
-        // Calculate current price based on linear decay
+        // Calculate current price on the auction's decay curve
         let current_price = calculate_current_price(
+            &auction.price_curve,
             auction.start_price,
             auction.reserve_price,
             auction.start_time,
-            auction.end_time,
+            auction.curve_end_time,
             clock.unix_timestamp,
         )?;
+        require!(current_price <= max_price_per_token, ErrorCode::PriceAboveLimit);
 
         auction.current_price = current_price;
 
-        let total_cost = token_amount
-            .checked_mul(current_price)
+        let total_cost = auction_pricing::bid_cost(token_amount, current_price)
             .ok_or(ErrorCode::MathOverflow)?;
 
         // Transfer USDC from bidder to escrow
@@ -80,26 +188,204 @@ pub mod dutch_auction {
             total_cost,
         )?;
 
-        // Record bid
-        bid.auction = auction.key();
-        bid.bidder = ctx.accounts.bidder.key();
-        bid.token_amount = token_amount;
-        bid.price_per_token = current_price;
-        bid.total_cost = total_cost;
-        bid.timestamp = clock.unix_timestamp;
-        bid.status = BidStatus::Pending;
-
-        // Update auction state
-        auction.tokens_remaining -= token_amount;
-        auction.total_raised += total_cost;
-        auction.participant_count += 1;
+        record_fill(
+            auction,
+            bid,
+            &mut ctx.accounts.bidder_position,
+            ctx.accounts.bidder.key(),
+            token_amount,
+            current_price,
+            total_cost,
+        )?;
+
+        msg!("Bid placed: {} CCT at {} USDC/token", token_amount, current_price);
+        Ok(())
+    }
+
+    /// Rest a limit order: escrow `token_amount` at `limit_price` USDC now and let anyone fill it
+    /// with `fill_limit_order` once the decaying price reaches `limit_price`
+    pub fn place_limit_order(
+        ctx: Context<PlaceLimitOrder>,
+        token_amount: u64,
+        limit_price: u64,
+    ) -> Result<()> {
+        let auction = &ctx.accounts.auction;
+        let order = &mut ctx.accounts.limit_order;
+        let clock = Clock::get()?;
+
+        // Orders may rest on a scheduled auction before it opens
+        require!(auction.kind == AuctionKind::Dutch, ErrorCode::WrongAuctionKind);
+        ctx.accounts.industry_account.ensure_in_standing(false)?;
+        require!(
+            auction.status == AuctionStatus::Active || auction.status == AuctionStatus::Scheduled,
+            ErrorCode::AuctionNotActive
+        );
+        require!(clock.unix_timestamp < auction.end_time, ErrorCode::AuctionEnded);
+        require!(
+            token_amount > 0 && limit_price >= auction.reserve_price,
+            ErrorCode::InvalidLimitOrder
+        );
+        check_bid_size(auction, &ctx.accounts.bidder_position, token_amount)?;
+
+        let deposit = auction_pricing::bid_cost(token_amount, limit_price)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bidder_usdc.to_account_info(),
+                    to: ctx.accounts.escrow_usdc.to_account_info(),
+                    authority: ctx.accounts.bidder.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+
+        order.auction = auction.key();
+        order.bidder = ctx.accounts.bidder.key();
+        order.token_amount = token_amount;
+        order.limit_price = limit_price;
+        order.deposit = deposit;
+        order.status = LimitOrderStatus::Open;
+        order.bid = Pubkey::default();
+        order.created_at = clock.unix_timestamp;
+        order.filled_at = 0;
+
+        // Open the position now so the crank never pays for it
+        let position = &mut ctx.accounts.bidder_position;
+        position.auction = order.auction;
+        position.bidder = order.bidder;
+
+        emit!(LimitOrderPlaced {
+            auction: order.auction,
+            limit_order: order.key(),
+            bidder: order.bidder,
+            token_amount,
+            limit_price,
+            deposit,
+        });
+
+        msg!("Limit order placed: {} CCT at up to {} USDC/token", token_amount, limit_price);
+        Ok(())
+    }
+
+    /// Fill a resting limit order at the current price (permissionless crank). The cranker
+    /// pays rent for the resulting bid.
+    pub fn fill_limit_order(ctx: Context<FillLimitOrder>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let order = &mut ctx.accounts.limit_order;
+        let bid = &mut ctx.accounts.bid;
+        let clock = Clock::get()?;
+
+        require!(auction.kind == AuctionKind::Dutch, ErrorCode::WrongAuctionKind);
+        activate_if_started(auction, clock.unix_timestamp);
+        require!(auction.status != AuctionStatus::Scheduled, ErrorCode::AuctionNotStarted);
+        require!(auction.status == AuctionStatus::Active, ErrorCode::AuctionNotActive);
+        require!(clock.unix_timestamp < auction.end_time, ErrorCode::AuctionEnded);
+        require!(order.status == LimitOrderStatus::Open, ErrorCode::OrderNotOpen);
+        // The fill places a bid, so the owner must still be in standing; until reinstated the
+        // order stays open and the owner can close it for a refund
+        ctx.accounts.industry_account.ensure_in_standing(false)?;
+
+        let current_price = calculate_current_price(
+            &auction.price_curve,
+            auction.start_price,
+            auction.reserve_price,
+            auction.start_time,
+            auction.curve_end_time,
+            clock.unix_timestamp,
+        )?;
+        require!(current_price <= order.limit_price, ErrorCode::LimitNotReached);
+
+        auction.current_price = current_price;
+
+        // The auction may sell out, or the bidder may have bought up to their cap since placing
+        // the order, before the whole order can be filled
+        let allowance = calculate_bidder_allowance(
+            &auction.bid_limits,
+            auction.total_tokens,
+            ctx.accounts.bidder_position.tokens_bought,
+        )?;
+        let token_amount = order
+            .token_amount
+            .min(auction.tokens_remaining)
+            .min(allowance);
+        let total_cost = auction_pricing::bid_cost(token_amount, current_price)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // The bid's USDC is already in escrow as part of the order deposit
+        order.deposit = order
+            .deposit
+            .checked_sub(total_cost)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        record_fill(
+            auction,
+            bid,
+            &mut ctx.accounts.bidder_position,
+            order.bidder,
+            token_amount,
+            current_price,
+            total_cost,
+        )?;
+
+        order.status = LimitOrderStatus::Filled;
+        order.bid = bid.key();
+        order.filled_at = clock.unix_timestamp;
+
+        emit!(LimitOrderFilled {
+            auction: auction.key(),
+            limit_order: order.key(),
+            bid: order.bid,
+            bidder: order.bidder,
+            token_amount,
+            price_per_token: current_price,
+            cranker: ctx.accounts.cranker.key(),
+        });
+
+        msg!("Limit order filled: {} CCT at {} USDC/token", token_amount, current_price);
+        Ok(())
+    }
+
+    /// Close a limit order, returning its unspent deposit: everything if it never filled,
+    /// otherwise the difference between the limit and the fill price
+    pub fn close_limit_order(ctx: Context<CloseLimitOrder>) -> Result<()> {
+        let auction_key = ctx.accounts.auction.key();
+        let order = &ctx.accounts.limit_order;
+        let refund = order.deposit;
+
+        if refund > 0 {
+            let seeds = &[
+                b"escrow_authority".as_ref(),
+                auction_key.as_ref(),
+                &[ctx.bumps.escrow_authority],
+            ];
+            let signer = &[&seeds[..]];
 
-        // Check if auction should close
-        if auction.tokens_remaining == 0 {
-            auction.status = AuctionStatus::Completed;
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow_usdc.to_account_info(),
+                        to: ctx.accounts.bidder_usdc.to_account_info(),
+                        authority: ctx.accounts.escrow_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                refund,
+            )?;
         }
 
-        msg!("Bid placed: {} CCT at {} USDC/token", token_amount, current_price);
+        emit!(LimitOrderClosed {
+            auction: auction_key,
+            limit_order: order.key(),
+            bidder: order.bidder,
+            filled: order.status == LimitOrderStatus::Filled,
+            refund,
+        });
+
+        msg!("Limit order closed, refund: {} USDC", refund);
         Ok(())
     }
 
@@ -108,6 +394,8 @@ pub mod dutch_auction {
         let auction = &mut ctx.accounts.auction;
         let clock = Clock::get()?;
 
+        require!(auction.kind == AuctionKind::Dutch, ErrorCode::WrongAuctionKind);
+        activate_if_started(auction, clock.unix_timestamp);
         require!(
             auction.status == AuctionStatus::Active || auction.status == AuctionStatus::Completed,
             ErrorCode::InvalidAuctionStatus
@@ -117,39 +405,55 @@ pub mod dutch_auction {
             ErrorCode::AuctionNotEnded
         );
 
-        // Calculate clearing price (last accepted bid price or reserve)
-        let clearing_price = if auction.tokens_remaining == 0 {
-            auction.current_price
-        } else {
-            auction.reserve_price
-        };
+        let clearing_price = calculate_final_clearing_price(auction)?;
+        let settled = calculate_settled_proceeds(auction, clearing_price)?;
+        if settled < auction.min_raise {
+            return fail_auction(auction, settled, clock.unix_timestamp);
+        }
 
         auction.status = AuctionStatus::Finalized;
         auction.current_price = clearing_price;
-
-        msg!("Auction #{} finalized. Clearing price: {}", auction.batch_number, clearing_price);
+        auction.clearing_price = clearing_price;
+
+        // CCT is only minted on claim, so unsold supply simply stays with the issuer
+        auction.unsold_tokens = auction.tokens_remaining;
+
+        emit!(AuctionFinalized {
+            auction: auction.key(),
+            batch_number: auction.batch_number,
+            clearing_price,
+            tokens_sold: auction.total_tokens - auction.unsold_tokens,
+            unsold_tokens: auction.unsold_tokens,
+            total_raised: auction.total_raised,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Auction #{} finalized. Clearing price: {}, unsold: {} CCT returned to issuer",
+            auction.batch_number,
+            clearing_price,
+            auction.unsold_tokens
+        );
         Ok(())
     }
 
-    /// Claim tokens after auction finalization
+    /// Claim tokens after auction finalization (CCT issued via carbon-credits CPI)
     pub fn claim_tokens(ctx: Context<ClaimTokens>) -> Result<()> {
         let auction = &ctx.accounts.auction;
+        let auction_key = auction.key();
         let bid = &mut ctx.accounts.bid;
 
         require!(auction.status == AuctionStatus::Finalized, ErrorCode::AuctionNotFinalized);
         require!(bid.status == BidStatus::Pending, ErrorCode::BidAlreadyProcessed);
 
-        let clearing_price = auction.current_price;
-        let refund_amount = bid
-            .total_cost
-            .checked_sub(bid.token_amount * clearing_price)
-            .ok_or(ErrorCode::MathOverflow)?;
+        let refund_amount =
+            calculate_refund(bid.token_amount, bid.total_cost, auction.clearing_price)?;
 
         // Refund excess USDC if bid price > clearing price
         if refund_amount > 0 {
             let seeds = &[
-                b"escrow_authority",
-                auction.key().as_ref(),
+                b"escrow_authority".as_ref(),
+                auction_key.as_ref(),
                 &[ctx.bumps.escrow_authority],
             ];
             let signer = &[&seeds[..]];
@@ -168,173 +472,1938 @@ pub mod dutch_auction {
             )?;
         }
 
+        // Deliver won CCT and credit the bidder's IndustryAccount
+        let issuer_seeds = &[b"cct_issuer".as_ref(), &[ctx.bumps.issuer]];
+        let issuer_signer = &[&issuer_seeds[..]];
+
+        carbon_credits::cpi::issue_auction_cct(
+            CpiContext::new_with_signer(
+                ctx.accounts.carbon_credits_program.to_account_info(),
+                carbon_credits::cpi::accounts::IssueAuctionCCT {
+                    config: ctx.accounts.cct_config.to_account_info(),
+                    industry_account: ctx.accounts.industry_account.to_account_info(),
+                    cct_mint: ctx.accounts.cct_mint.to_account_info(),
+                    industry_cct_account: ctx.accounts.bidder_cct.to_account_info(),
+                    mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                    issuer: ctx.accounts.issuer.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+                issuer_signer,
+            ),
+            bid.token_amount,
+        )?;
+
         bid.status = BidStatus::Accepted;
 
+        emit!(TokensClaimed {
+            auction: auction_key,
+            bid: bid.key(),
+            bidder: bid.bidder,
+            token_amount: bid.token_amount,
+            clearing_price: auction.clearing_price,
+            refund: refund_amount,
+        });
+
         msg!("Tokens claimed: {} CCT, refund: {} USDC", bid.token_amount, refund_amount);
         Ok(())
     }
 
-    /// Cancel auction (admin only, before any bids)
-    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+    /// Withdraw auction proceeds (clearing price * tokens sold) to the treasury
+    pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
         let auction = &mut ctx.accounts.auction;
+        let auction_key = auction.key();
 
-        require!(auction.status == AuctionStatus::Active, ErrorCode::InvalidAuctionStatus);
-        require!(auction.participant_count == 0, ErrorCode::HasParticipants);
+        require!(auction.status == AuctionStatus::Finalized, ErrorCode::AuctionNotFinalized);
 
-        auction.status = AuctionStatus::Cancelled;
+        // Everything above clearing_price * tokens_sold is owed back to bidders as refunds
+        let tokens_sold = auction
+            .total_tokens
+            .checked_sub(auction.tokens_remaining)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let proceeds = calculate_proceeds(tokens_sold, auction.clearing_price)?;
+        let amount = proceeds
+            .checked_sub(auction.proceeds_withdrawn)
+            .ok_or(ErrorCode::MathOverflow)?;
 
-        msg!("Auction #{} cancelled", auction.batch_number);
-        Ok(())
-    }
-}
+        require!(amount > 0, ErrorCode::NothingToWithdraw);
 
-// ============================================================================
-// Helper Functions
-// ============================================================================
+        let seeds = &[
+            b"escrow_authority".as_ref(),
+            auction_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ];
+        let signer = &[&seeds[..]];
 
-fn calculate_current_price(
-    start_price: u64,
-    reserve_price: u64,
-    start_time: i64,
-    end_time: i64,
-    current_time: i64,
-) -> Result<u64> {
-    if current_time >= end_time {
-        return Ok(reserve_price);
-    }
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_usdc.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.escrow_authority.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
 
-    if current_time <= start_time {
-        return Ok(start_price);
-    }
+        auction.proceeds_withdrawn = proceeds;
 
-    let duration = end_time - start_time;
-    let elapsed = current_time - start_time;
-    let price_range = start_price - reserve_price;
+        emit!(ProceedsWithdrawn {
+            auction: auction_key,
+            treasury: auction.treasury,
+            amount,
+            proceeds_withdrawn: auction.proceeds_withdrawn,
+        });
 
-    // Linear decay: price = start_price - (price_range * elapsed / duration)
-    let decay = (price_range as u128)
-        .checked_mul(elapsed as u128)
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(duration as u128)
-        .ok_or(ErrorCode::MathOverflow)? as u64;
+        msg!("Auction #{} proceeds withdrawn: {} USDC", auction.batch_number, amount);
+        Ok(())
+    }
 
-    let current_price = start_price
-        .checked_sub(decay)
-        .ok_or(ErrorCode::MathOverflow)?;
+    /// Cancel an auction before it is finalized (admin only). Bids already placed are returned
+    /// in full through `refund_bid`.
+    pub fn cancel_auction(ctx: Context<CancelAuction>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
 
-    Ok(current_price.max(reserve_price))
-}
+        require!(
+            matches!(
+                auction.status,
+                AuctionStatus::Scheduled | AuctionStatus::Active | AuctionStatus::Completed
+            ),
+            ErrorCode::InvalidAuctionStatus
+        );
 
-// ============================================================================
-// Contexts
-// ============================================================================
+        auction.status = AuctionStatus::Cancelled;
 
-#[derive(Accounts)]
-#[instruction(batch_number: u32)]
-pub struct CreateAuction<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Auction::INIT_SPACE,
-        seeds = [b"auction", &batch_number.to_le_bytes()],
-        bump,
-    )]
-    pub auction: Account<'info, Auction>,
+        emit!(AuctionCancelled {
+            auction: auction.key(),
+            batch_number: auction.batch_number,
+            participant_count: auction.participant_count,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Auction #{} cancelled, {} bids to refund",
+            auction.batch_number,
+            auction.participant_count
+        );
+        Ok(())
+    }
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// Return a bid's full `total_cost` from escrow once its auction is cancelled or has failed
+    /// to reach `min_raise`. Permissionless: the USDC can only go to the bidder.
+    pub fn refund_bid(ctx: Context<RefundBid>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let auction_key = auction.key();
+        let bid = &mut ctx.accounts.bid;
+        let clock = Clock::get()?;
 
-    pub system_program: Program<'info, System>,
-}
+        // An auction that ended short of its minimum raise fails without waiting for the admin
+        if auction.kind == AuctionKind::Dutch
+            && (auction.status == AuctionStatus::Active
+                || auction.status == AuctionStatus::Completed)
+            && (clock.unix_timestamp >= auction.end_time || auction.tokens_remaining == 0)
+        {
+            let clearing_price = calculate_final_clearing_price(auction)?;
+            let settled = calculate_settled_proceeds(auction, clearing_price)?;
+            if settled < auction.min_raise {
+                fail_auction(auction, settled, clock.unix_timestamp)?;
+            }
+        }
 
-#[derive(Accounts)]
-pub struct PlaceBid<'info> {
-    #[account(
-        mut,
-        seeds = [b"auction", &auction.batch_number.to_le_bytes()],
-        bump,
-    )]
-    pub auction: Account<'info, Auction>,
+        require!(
+            auction.status == AuctionStatus::Cancelled || auction.status == AuctionStatus::Failed,
+            ErrorCode::AuctionNotRefundable
+        );
+        require!(bid.status == BidStatus::Pending, ErrorCode::BidAlreadyProcessed);
 
-    #[account(
-        init,
-        payer = bidder,
-        space = 8 + Bid::INIT_SPACE,
-        seeds = [
-            b"bid",
-            auction.key().as_ref(),
-            bidder.key().as_ref(),
-            &Clock::get()?.unix_timestamp.to_le_bytes()
-        ],
-        bump,
-    )]
-    pub bid: Account<'info, Bid>,
+        let seeds = &[
+            b"escrow_authority".as_ref(),
+            auction_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ];
+        let signer = &[&seeds[..]];
 
-    #[account(mut)]
-    pub bidder_usdc: Account<'info, TokenAccount>,
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_usdc.to_account_info(),
+                    to: ctx.accounts.bidder_usdc.to_account_info(),
+                    authority: ctx.accounts.escrow_authority.to_account_info(),
+                },
+                signer,
+            ),
+            bid.total_cost,
+        )?;
 
-    #[account(mut)]
-    pub escrow_usdc: Account<'info, TokenAccount>,
+        bid.status = BidStatus::Refunded;
 
-    #[account(mut)]
-    pub bidder: Signer<'info>,
+        emit!(BidRefunded {
+            auction: auction_key,
+            bid: bid.key(),
+            bidder: bid.bidder,
+            amount: bid.total_cost,
+            timestamp: clock.unix_timestamp,
+        });
 
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+        msg!("Bid refunded: {} USDC", bid.total_cost);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct FinalizeAuction<'info> {
-    #[account(
-        mut,
-        seeds = [b"auction", &auction.batch_number.to_le_bytes()],
-        bump,
-    )]
-    pub auction: Account<'info, Auction>,
+    /// Create a sealed-bid auction: commitments are accepted for `commit_seconds`, then
+    /// revealed for `reveal_seconds`, and everyone pays one uniform clearing price
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_sealed_auction(
+        ctx: Context<CreateSealedAuction>,
+        batch_number: u32,
+        total_tokens: u64,
+        reserve_price: u64,
+        commit_seconds: i64,
+        reveal_seconds: i64,
+        forfeit_bps: u16,
+        bid_limits: BidLimits,
+    ) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let clock = Clock::get()?;
 
-    /// CHECK: Admin authority
-    pub authority: Signer<'info>,
-}
+        require!(commit_seconds > 0 && reveal_seconds > 0, ErrorCode::InvalidDuration);
+        require!(
+            u64::from(forfeit_bps) <= auction_pricing::BPS_DENOMINATOR,
+            ErrorCode::InvalidForfeit
+        );
+        require!(bid_limits.is_valid(total_tokens), ErrorCode::InvalidBidLimits);
 
-#[derive(Accounts)]
-pub struct ClaimTokens<'info> {
-    #[account(
-        seeds = [b"auction", &auction.batch_number.to_le_bytes()],
-        bump,
-    )]
-    pub auction: Account<'info, Auction>,
+        let end_time = clock
+            .unix_timestamp
+            .checked_add(commit_seconds)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let reveal_end = end_time
+            .checked_add(reveal_seconds)
+            .ok_or(ErrorCode::MathOverflow)?;
 
-    #[account(mut)]
-    pub bid: Account<'info, Bid>,
+        auction.authority = ctx.accounts.authority.key();
+        auction.batch_number = batch_number;
+        auction.series = Pubkey::default();
+        auction.total_tokens = total_tokens;
+        auction.tokens_remaining = total_tokens;
+        auction.start_price = reserve_price;
+        auction.current_price = reserve_price;
+        auction.reserve_price = reserve_price;
+        auction.price_curve = PriceCurve::Linear;
+        auction.bid_limits = bid_limits;
+        auction.kind = AuctionKind::SealedBid {
+            reveal_end,
+            forfeit_bps,
+        };
+        auction.extension = ExtensionPolicy::default();
+        auction.min_raise = 0;
+        auction.start_time = clock.unix_timestamp;
+        auction.end_time = end_time;
+        auction.curve_end_time = end_time;
+        auction.status = AuctionStatus::Active;
+        auction.total_raised = 0;
+        auction.participant_count = 0;
+        auction.usdc_mint = ctx.accounts.usdc_mint.key();
+        auction.treasury = ctx.accounts.treasury.key();
+        auction.proceeds_withdrawn = 0;
+        auction.lowest_fill_price = reserve_price;
+        auction.clearing_price = 0;
+        auction.unsold_tokens = 0;
+
+        ctx.accounts.sealed_book.auction = auction.key();
+
+        emit!(AuctionCreated {
+            auction: auction.key(),
+            authority: auction.authority,
+            batch_number,
+            total_tokens,
+            start_price: reserve_price,
+            reserve_price,
+            price_curve: auction.price_curve,
+            bid_limits,
+            kind: auction.kind.clone(),
+            extension: auction.extension,
+            min_raise: 0,
+            start_time: auction.start_time,
+            end_time,
+        });
+
+        msg!("Sealed-bid auction #{} created: {} CCT tokens", batch_number, total_tokens);
+        Ok(())
+    }
+
+    /// Commit to a sealed bid (see `sealed_bid_commitment`) and escrow `deposit` USDC, which
+    /// must cover the revealed bid
+    pub fn commit_bid(ctx: Context<CommitBid>, commitment: [u8; 32], deposit: u64) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let sealed_bid = &mut ctx.accounts.sealed_bid;
+        let clock = Clock::get()?;
+
+        require!(
+            matches!(auction.kind, AuctionKind::SealedBid { .. }),
+            ErrorCode::WrongAuctionKind
+        );
+        ctx.accounts.industry_account.ensure_in_standing(false)?;
+        require!(auction.status == AuctionStatus::Active, ErrorCode::AuctionNotActive);
+        require!(clock.unix_timestamp < auction.end_time, ErrorCode::CommitPhaseOver);
+        require!(deposit > 0, ErrorCode::DepositTooSmall);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bidder_usdc.to_account_info(),
+                    to: ctx.accounts.escrow_usdc.to_account_info(),
+                    authority: ctx.accounts.bidder.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+
+        sealed_bid.auction = auction.key();
+        sealed_bid.bidder = ctx.accounts.bidder.key();
+        sealed_bid.commitment = commitment;
+        sealed_bid.deposit = deposit;
+        sealed_bid.revealed = false;
+        sealed_bid.token_amount = 0;
+        sealed_bid.price_per_token = 0;
+        sealed_bid.booked = false;
+        sealed_bid.committed_at = clock.unix_timestamp;
+        sealed_bid.status = BidStatus::Pending;
+
+        auction.total_raised = auction
+            .total_raised
+            .checked_add(deposit)
+            .ok_or(ErrorCode::MathOverflow)?;
+        auction.participant_count += 1;
+
+        emit!(SealedBidCommitted {
+            auction: auction.key(),
+            sealed_bid: sealed_bid.key(),
+            bidder: sealed_bid.bidder,
+            deposit,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Sealed bid committed with {} USDC deposit", deposit);
+        Ok(())
+    }
+
+    /// Reveal a committed bid. A bid that is never revealed, or cannot be revealed because it
+    /// breaks the auction's rules, forfeits `forfeit_bps` of its deposit.
+    pub fn reveal_bid(
+        ctx: Context<RevealBid>,
+        token_amount: u64,
+        price_per_token: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        let auction = &ctx.accounts.auction;
+        let sealed_bid = &mut ctx.accounts.sealed_bid;
+        let book = &mut ctx.accounts.sealed_book;
+        let clock = Clock::get()?;
+
+        let AuctionKind::SealedBid { reveal_end, .. } = auction.kind else {
+            return err!(ErrorCode::WrongAuctionKind);
+        };
+        require!(auction.status == AuctionStatus::Active, ErrorCode::AuctionNotActive);
+        require!(
+            clock.unix_timestamp >= auction.end_time && clock.unix_timestamp < reveal_end,
+            ErrorCode::NotRevealPhase
+        );
+        require!(!sealed_bid.revealed, ErrorCode::AlreadyRevealed);
+        require!(
+            sealed_bid.commitment
+                == sealed_bid_commitment(
+                    &sealed_bid.bidder,
+                    token_amount,
+                    price_per_token,
+                    &salt
+                ),
+            ErrorCode::CommitmentMismatch
+        );
+
+        require!(price_per_token >= auction.reserve_price, ErrorCode::PriceBelowReserve);
+        require!(
+            token_amount > 0 && token_amount >= auction.bid_limits.min_bid_tokens,
+            ErrorCode::BidBelowMinimum
+        );
+        let allowance = calculate_bidder_allowance(&auction.bid_limits, auction.total_tokens, 0)?;
+        require!(token_amount <= allowance, ErrorCode::BidderCapExceeded);
+
+        let total_cost = auction_pricing::bid_cost(token_amount, price_per_token)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(total_cost <= sealed_bid.deposit, ErrorCode::DepositTooSmall);
+
+        // Once every price level is taken, a reveal at a new price is still accepted so the
+        // bidder keeps the whole deposit, but it sits out the clearing
+        let booked = book.has_room_for(price_per_token);
+        if booked {
+            book.add_demand(price_per_token, token_amount)?;
+        }
+
+        sealed_bid.revealed = true;
+        sealed_bid.token_amount = token_amount;
+        sealed_bid.price_per_token = price_per_token;
+        sealed_bid.booked = booked;
+
+        emit!(SealedBidRevealed {
+            auction: auction.key(),
+            sealed_bid: sealed_bid.key(),
+            bidder: sealed_bid.bidder,
+            token_amount,
+            price_per_token,
+            booked,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Sealed bid revealed: {} CCT at {} USDC/token", token_amount, price_per_token);
+        Ok(())
+    }
+
+    /// Compute the uniform clearing price over the revealed bids once the reveal window closes
+    pub fn finalize_sealed_auction(ctx: Context<FinalizeSealedAuction>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let book = &mut ctx.accounts.sealed_book;
+        let clock = Clock::get()?;
+
+        let AuctionKind::SealedBid { reveal_end, .. } = auction.kind else {
+            return err!(ErrorCode::WrongAuctionKind);
+        };
+        require!(auction.status == AuctionStatus::Active, ErrorCode::InvalidAuctionStatus);
+        require!(clock.unix_timestamp >= reveal_end, ErrorCode::AuctionNotEnded);
+
+        let clearing = auction_pricing::sealed_clearing(
+            book.levels.iter().map(|level| (level.price, level.tokens)),
+            auction.total_tokens,
+            auction.reserve_price,
+        )
+        .ok_or(ErrorCode::MathOverflow)?;
+
+        book.marginal_demand = clearing.marginal_demand;
+        book.marginal_supply = clearing.marginal_supply;
+
+        auction.status = AuctionStatus::Finalized;
+        auction.current_price = clearing.clearing_price;
+        auction.clearing_price = clearing.clearing_price;
+        auction.lowest_fill_price = clearing.clearing_price;
+
+        // Tokens are booked as sold when each winner claims, so proceeds never run ahead of
+        // the rounded-down pro-rata fills actually charged
+        auction.unsold_tokens = auction.total_tokens - clearing.tokens_allocated;
+
+        // Deposits are mostly refunded; report what the winners pay
+        let settled = calculate_proceeds(clearing.tokens_allocated, clearing.clearing_price)?;
+
+        emit!(AuctionFinalized {
+            auction: auction.key(),
+            batch_number: auction.batch_number,
+            clearing_price: clearing.clearing_price,
+            tokens_sold: clearing.tokens_allocated,
+            unsold_tokens: auction.unsold_tokens,
+            total_raised: settled,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Sealed-bid auction #{} finalized. Clearing price: {}, unsold: {} CCT",
+            auction.batch_number,
+            clearing.clearing_price,
+            auction.unsold_tokens
+        );
+        Ok(())
+    }
+
+    /// Settle a sealed bid: issue the won CCT and refund the deposit above
+    /// `clearing_price * tokens won`, or for an unrevealed bid send the forfeit to the treasury
+    /// and refund the rest
+    pub fn claim_sealed_bid(ctx: Context<ClaimSealedBid>) -> Result<()> {
+        let auction = &mut ctx.accounts.auction;
+        let auction_key = auction.key();
+        let sealed_bid = &mut ctx.accounts.sealed_bid;
+
+        let AuctionKind::SealedBid { forfeit_bps, .. } = auction.kind else {
+            return err!(ErrorCode::WrongAuctionKind);
+        };
+        require!(
+            matches!(auction.status, AuctionStatus::Finalized | AuctionStatus::Cancelled),
+            ErrorCode::AuctionNotFinalized
+        );
+        require!(sealed_bid.status == BidStatus::Pending, ErrorCode::BidAlreadyProcessed);
+
+        // A cancelled auction, or a reveal that found the book full, returns the whole deposit
+        let refund_in_full = auction.status == AuctionStatus::Cancelled
+            || (sealed_bid.revealed && !sealed_bid.booked);
+        let (tokens_won, forfeit) = if refund_in_full {
+            (0, 0)
+        } else if sealed_bid.revealed {
+            let clearing = ctx.accounts.sealed_book.clearing(auction.clearing_price);
+            let tokens_won = auction_pricing::pro_rata_fill(
+                sealed_bid.token_amount,
+                sealed_bid.price_per_token,
+                &clearing,
+            )
+            .ok_or(ErrorCode::MathOverflow)?;
+            (tokens_won, 0)
+        } else {
+            let forfeit = auction_pricing::forfeit(sealed_bid.deposit, forfeit_bps)
+                .ok_or(ErrorCode::MathOverflow)?;
+            (0, forfeit)
+        };
+
+        let charged = calculate_proceeds(tokens_won, auction.clearing_price)?;
+        let refund = sealed_bid
+            .deposit
+            .checked_sub(charged)
+            .and_then(|rest| rest.checked_sub(forfeit))
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let seeds = &[
+            b"escrow_authority".as_ref(),
+            auction_key.as_ref(),
+            &[ctx.bumps.escrow_authority],
+        ];
+        let signer = &[&seeds[..]];
+
+        if refund > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow_usdc.to_account_info(),
+                        to: ctx.accounts.bidder_usdc.to_account_info(),
+                        authority: ctx.accounts.escrow_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                refund,
+            )?;
+        }
+
+        if forfeit > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.escrow_usdc.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                        authority: ctx.accounts.escrow_authority.to_account_info(),
+                    },
+                    signer,
+                ),
+                forfeit,
+            )?;
+        }
+
+        if tokens_won > 0 {
+            let issuer_seeds = &[b"cct_issuer".as_ref(), &[ctx.bumps.issuer]];
+            let issuer_signer = &[&issuer_seeds[..]];
+
+            carbon_credits::cpi::issue_auction_cct(
+                CpiContext::new_with_signer(
+                    ctx.accounts.carbon_credits_program.to_account_info(),
+                    carbon_credits::cpi::accounts::IssueAuctionCCT {
+                        config: ctx.accounts.cct_config.to_account_info(),
+                        industry_account: ctx.accounts.industry_account.to_account_info(),
+                        cct_mint: ctx.accounts.cct_mint.to_account_info(),
+                        industry_cct_account: ctx.accounts.bidder_cct.to_account_info(),
+                        mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                        issuer: ctx.accounts.issuer.to_account_info(),
+                        token_program: ctx.accounts.token_program.to_account_info(),
+                    },
+                    issuer_signer,
+                ),
+                tokens_won,
+            )?;
+
+            // Sold tokens unlock the matching proceeds for `withdraw_proceeds`
+            auction.tokens_remaining = auction
+                .tokens_remaining
+                .checked_sub(tokens_won)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        sealed_bid.status = if tokens_won > 0 {
+            BidStatus::Accepted
+        } else {
+            BidStatus::Refunded
+        };
+
+        emit!(SealedBidSettled {
+            auction: auction_key,
+            sealed_bid: sealed_bid.key(),
+            bidder: sealed_bid.bidder,
+            revealed: sealed_bid.revealed,
+            tokens_won,
+            clearing_price: auction.clearing_price,
+            refund,
+            forfeit,
+        });
+
+        msg!(
+            "Sealed bid settled: {} CCT, refund: {} USDC, forfeit: {} USDC",
+            tokens_won,
+            refund,
+            forfeit
+        );
+        Ok(())
+    }
+
+    /// Open a series of recurring batches drawn from one supply budget. Batches are numbered
+    /// from `first_batch_number` up and created by `launch_next_batch` from `template`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_series(
+        ctx: Context<CreateSeries>,
+        series_id: u32,
+        first_batch_number: u32,
+        supply_budget: u64,
+        tokens_per_batch: u64,
+        template: SeriesTemplate,
+        adaptive_start_bps: Option<u16>,
+    ) -> Result<()> {
+        let series = &mut ctx.accounts.series;
+
+        require!(
+            tokens_per_batch > 0 && tokens_per_batch <= supply_budget,
+            ErrorCode::InvalidSeriesSupply
+        );
+        require!(template.is_valid(tokens_per_batch), ErrorCode::InvalidSeriesTemplate);
+
+        series.authority = ctx.accounts.authority.key();
+        series.series_id = series_id;
+        series.next_batch_number = first_batch_number;
+        series.batches_launched = 0;
+        series.supply_budget = supply_budget;
+        series.supply_sold = 0;
+        series.tokens_per_batch = tokens_per_batch;
+        series.template = template;
+        series.adaptive_start_bps = adaptive_start_bps;
+        series.usdc_mint = ctx.accounts.usdc_mint.key();
+        series.treasury = ctx.accounts.treasury.key();
+
+        emit!(SeriesCreated {
+            series: series.key(),
+            authority: series.authority,
+            series_id,
+            first_batch_number,
+            supply_budget,
+            tokens_per_batch,
+            template,
+            adaptive_start_bps,
+        });
+
+        msg!(
+            "Auction series #{} created: {} CCT in batches of {}",
+            series_id,
+            supply_budget,
+            tokens_per_batch
+        );
+        Ok(())
+    }
+
+    /// Create the series' next batch from its template once the previous batch is settled
+    /// (finalized, failed or cancelled). Only CCT actually sold counts against the budget.
+    pub fn launch_next_batch(ctx: Context<LaunchNextBatch>, start_time: i64) -> Result<()> {
+        let series = &mut ctx.accounts.series;
+        let auction = &mut ctx.accounts.auction;
+        let clock = Clock::get()?;
+
+        let mut previous_clearing_price = None;
+        if series.batches_launched > 0 {
+            let previous = ctx
+                .accounts
+                .previous_auction
+                .as_ref()
+                .ok_or(ErrorCode::PreviousBatchNotSettled)?;
+
+            let tokens_sold = match previous.status {
+                AuctionStatus::Finalized => {
+                    previous_clearing_price = Some(previous.clearing_price);
+                    previous.total_tokens - previous.unsold_tokens
+                }
+                AuctionStatus::Failed | AuctionStatus::Cancelled => 0,
+                _ => return err!(ErrorCode::PreviousBatchNotSettled),
+            };
+            series.supply_sold = series
+                .supply_sold
+                .checked_add(tokens_sold)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+
+        let total_tokens = series
+            .supply_budget
+            .saturating_sub(series.supply_sold)
+            .min(series.tokens_per_batch);
+
+        let template = series.template;
+        // A remainder smaller than the minimum bid could never be sold
+        require!(
+            total_tokens > 0 && total_tokens >= template.bid_limits.min_bid_tokens,
+            ErrorCode::SeriesExhausted
+        );
+
+        let start_price = calculate_series_start_price(
+            &template,
+            series.adaptive_start_bps,
+            previous_clearing_price,
+        )?;
+        // A short final batch or a lower adaptive start must still be able to meet its floor
+        let min_raise = ((template.min_raise as u128 * total_tokens as u128)
+            / series.tokens_per_batch as u128) as u64;
+        let min_raise =
+            min_raise.min(auction_pricing::bid_cost(total_tokens, start_price).unwrap_or(u64::MAX));
+
+        configure_auction(
+            auction,
+            clock.unix_timestamp,
+            total_tokens,
+            start_price,
+            template.reserve_price,
+            start_time,
+            template.duration_seconds,
+            template.price_curve,
+            template.bid_limits,
+            template.extension,
+            min_raise,
+        )?;
+
+        let batch_number = series.next_batch_number;
+        auction.authority = ctx.accounts.authority.key();
+        auction.batch_number = batch_number;
+        auction.series = series.key();
+        auction.kind = AuctionKind::Dutch;
+        auction.total_raised = 0;
+        auction.participant_count = 0;
+        auction.usdc_mint = series.usdc_mint;
+        auction.treasury = series.treasury;
+        auction.proceeds_withdrawn = 0;
+        auction.clearing_price = 0;
+        auction.unsold_tokens = 0;
+
+        series.next_batch_number = batch_number
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        series.batches_launched += 1;
+
+        emit!(AuctionCreated {
+            auction: auction.key(),
+            authority: auction.authority,
+            batch_number,
+            total_tokens,
+            start_price,
+            reserve_price: template.reserve_price,
+            price_curve: template.price_curve,
+            bid_limits: template.bid_limits,
+            kind: auction.kind.clone(),
+            extension: template.extension,
+            min_raise: auction.min_raise,
+            start_time: auction.start_time,
+            end_time: auction.end_time,
+        });
+
+        emit!(SeriesBatchLaunched {
+            series: series.key(),
+            auction: auction.key(),
+            batch_number,
+            total_tokens,
+            start_price,
+            supply_sold: series.supply_sold,
+            supply_budget: series.supply_budget,
+        });
+
+        msg!(
+            "Series #{} launched batch #{}: {} CCT from {} USDC/token",
+            series.series_id,
+            batch_number,
+            total_tokens,
+            start_price
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+/// Book `token_amount` at `price_per_token` against the auction and the bidder's position and
+/// record it on `bid`. The caller has already moved `total_cost` into escrow.
+fn record_fill(
+    auction: &mut Account<Auction>,
+    bid: &mut Account<Bid>,
+    position: &mut Account<BidderPosition>,
+    bidder: Pubkey,
+    token_amount: u64,
+    price_per_token: u64,
+    total_cost: u64,
+) -> Result<()> {
+    check_bid_size(auction, position, token_amount)?;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    bid.auction = auction.key();
+    bid.bidder = bidder;
+    bid.token_amount = token_amount;
+    bid.price_per_token = price_per_token;
+    bid.total_cost = total_cost;
+    bid.timestamp = timestamp;
+    bid.status = BidStatus::Pending;
+
+    auction.tokens_remaining = auction
+        .tokens_remaining
+        .checked_sub(token_amount)
+        .ok_or(ErrorCode::InsufficientTokens)?;
+    auction.total_raised = auction
+        .total_raised
+        .checked_add(total_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+    auction.participant_count += 1;
+    auction.lowest_fill_price = auction.lowest_fill_price.min(price_per_token);
+
+    position.auction = auction.key();
+    position.bidder = bidder;
+    position.tokens_bought = position
+        .tokens_bought
+        .checked_add(token_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    position.total_cost = position
+        .total_cost
+        .checked_add(total_cost)
+        .ok_or(ErrorCode::MathOverflow)?;
+    position.bid_count += 1;
+
+    // Check if auction should close
+    if auction.tokens_remaining == 0 {
+        auction.status = AuctionStatus::Completed;
+    } else {
+        let end_time = calculate_extended_end_time(auction, timestamp)?;
+        if end_time > auction.end_time {
+            auction.end_time = end_time;
+
+            emit!(AuctionExtended {
+                auction: auction.key(),
+                end_time,
+                total_extension_seconds: end_time - auction.curve_end_time,
+                timestamp,
+            });
+        }
+    }
+
+    emit!(BidPlaced {
+        auction: auction.key(),
+        bid: bid.key(),
+        bidder,
+        token_amount,
+        price_per_token,
+        total_cost,
+        tokens_remaining: auction.tokens_remaining,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+/// Commitment a bidder submits to `commit_bid`: SHA-256 of the bid, the bidder's salt and
+/// their wallet, so a commitment cannot be replayed by someone else
+pub fn sealed_bid_commitment(
+    bidder: &Pubkey,
+    token_amount: u64,
+    price_per_token: u64,
+    salt: &[u8; 32],
+) -> [u8; 32] {
+    hashv(&[
+        &token_amount.to_le_bytes(),
+        &price_per_token.to_le_bytes(),
+        salt,
+        bidder.as_ref(),
+    ])
+    .to_bytes()
+}
+
+/// Validate and apply the parameters shared by `create_auction` and `update_auction`. The
+/// auction is `Scheduled` when `start_time` is still ahead of `now`.
+#[allow(clippy::too_many_arguments)]
+pub fn configure_auction(
+    auction: &mut Auction,
+    now: i64,
+    total_tokens: u64,
+    start_price: u64,
+    reserve_price: u64,
+    start_time: i64,
+    duration_seconds: i64,
+    price_curve: PriceCurve,
+    bid_limits: BidLimits,
+    extension: ExtensionPolicy,
+    min_raise: u64,
+) -> Result<()> {
+    require!(start_price > reserve_price, ErrorCode::InvalidPricing);
+    require!(duration_seconds > 0, ErrorCode::InvalidDuration);
+    require!(
+        auction_pricing::Curve::from(price_curve).is_valid(
+            start_price,
+            reserve_price,
+            duration_seconds
+        ),
+        ErrorCode::InvalidPriceCurve
+    );
+    require!(bid_limits.is_valid(total_tokens), ErrorCode::InvalidBidLimits);
+    require!(extension.is_valid(duration_seconds), ErrorCode::InvalidExtensionPolicy);
+    require!(
+        min_raise <= auction_pricing::bid_cost(total_tokens, start_price).unwrap_or(u64::MAX),
+        ErrorCode::InvalidMinRaise
+    );
+
+    let start_time = start_time.max(now);
+    let end_time = start_time
+        .checked_add(duration_seconds)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    auction.total_tokens = total_tokens;
+    auction.tokens_remaining = total_tokens;
+    auction.start_price = start_price;
+    auction.current_price = start_price;
+    auction.reserve_price = reserve_price;
+    auction.price_curve = price_curve;
+    auction.bid_limits = bid_limits;
+    auction.extension = extension;
+    auction.min_raise = min_raise;
+    auction.start_time = start_time;
+    auction.end_time = end_time;
+    auction.curve_end_time = end_time;
+    auction.lowest_fill_price = start_price;
+    auction.status = if start_time > now {
+        AuctionStatus::Scheduled
+    } else {
+        AuctionStatus::Active
+    };
+
+    Ok(())
+}
+
+/// Start price of a series' next batch. With `adaptive_start_bps` set, a batch following a
+/// finalized one opens that far above the previous clearing price, kept within
+/// (`reserve_price`, template `start_price`]; if the template's curve cannot start there it
+/// falls back to the template price.
+pub fn calculate_series_start_price(
+    template: &SeriesTemplate,
+    adaptive_start_bps: Option<u16>,
+    previous_clearing_price: Option<u64>,
+) -> Result<u64> {
+    let (Some(markup_bps), Some(clearing_price)) = (adaptive_start_bps, previous_clearing_price)
+    else {
+        return Ok(template.start_price);
+    };
+
+    let adapted = (clearing_price as u128)
+        .checked_mul(auction_pricing::BPS_DENOMINATOR as u128 + markup_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / auction_pricing::BPS_DENOMINATOR as u128;
+    let start_price = adapted.clamp(
+        template.reserve_price as u128 + 1,
+        template.start_price as u128,
+    ) as u64;
+
+    let curve = auction_pricing::Curve::from(template.price_curve);
+    if curve.is_valid(start_price, template.reserve_price, template.duration_seconds) {
+        Ok(start_price)
+    } else {
+        Ok(template.start_price)
+    }
+}
+
+/// Open a scheduled auction once the clock has passed its `start_time`
+fn activate_if_started(auction: &mut Account<Auction>, now: i64) {
+    if auction.status == AuctionStatus::Scheduled && now >= auction.start_time {
+        auction.status = AuctionStatus::Active;
+
+        emit!(AuctionActivated {
+            auction: auction.key(),
+            batch_number: auction.batch_number,
+            start_time: auction.start_time,
+            timestamp: now,
+        });
+    }
+}
+
+/// Mark an auction whose `settled` proceeds fall short of `min_raise` as failed; every bid
+/// becomes refundable
+fn fail_auction(auction: &mut Account<Auction>, settled: u64, timestamp: i64) -> Result<()> {
+    auction.status = AuctionStatus::Failed;
+
+    emit!(AuctionFailed {
+        auction: auction.key(),
+        batch_number: auction.batch_number,
+        total_raised: settled,
+        min_raise: auction.min_raise,
+        timestamp,
+    });
+
+    msg!(
+        "Auction #{} failed: settles {} of {} USDC minimum",
+        auction.batch_number,
+        settled,
+        auction.min_raise
+    );
+    Ok(())
+}
+
+/// Enforce the auction's minimum bid size (waived for the final lot) and the bidder's cap
+fn check_bid_size(auction: &Auction, position: &BidderPosition, token_amount: u64) -> Result<()> {
+    require!(token_amount > 0, ErrorCode::BidBelowMinimum);
+    require!(
+        token_amount >= auction.bid_limits.min_bid_tokens
+            || token_amount == auction.tokens_remaining,
+        ErrorCode::BidBelowMinimum
+    );
+
+    let allowance = calculate_bidder_allowance(
+        &auction.bid_limits,
+        auction.total_tokens,
+        position.tokens_bought,
+    )?;
+    require!(token_amount <= allowance, ErrorCode::BidderCapExceeded);
+
+    Ok(())
+}
+
+/// Tokens a bidder who already won `tokens_bought` may still buy (see
+/// `auction_pricing::bidder_allowance`)
+pub fn calculate_bidder_allowance(
+    bid_limits: &BidLimits,
+    total_tokens: u64,
+    tokens_bought: u64,
+) -> Result<u64> {
+    Ok(auction_pricing::bidder_allowance(
+        total_tokens,
+        tokens_bought,
+        bid_limits.max_tokens_per_bidder,
+        bid_limits.max_share_bps,
+    )
+    .ok_or(ErrorCode::MathOverflow)?)
+}
+
+/// Closing time of `auction` after a bid at `current_time` (see
+/// `auction_pricing::extended_end_time`)
+pub fn calculate_extended_end_time(auction: &Auction, current_time: i64) -> Result<i64> {
+    Ok(auction_pricing::extended_end_time(
+        auction.curve_end_time,
+        auction.end_time,
+        current_time,
+        auction.extension.extension_window_seconds,
+        auction.extension.extension_seconds,
+        auction.extension.max_extension_seconds,
+    )
+    .ok_or(ErrorCode::MathOverflow)?)
+}
+
+/// Price at `current_time` on the auction's decay curve (see `auction_pricing::current_price`).
+/// Pass the auction's `curve_end_time` as `end_time`: after an anti-sniping extension the
+/// price stays at reserve instead of stretching the curve back up.
+pub fn calculate_current_price(
+    price_curve: &PriceCurve,
+    start_price: u64,
+    reserve_price: u64,
+    start_time: i64,
+    end_time: i64,
+    current_time: i64,
+) -> Result<u64> {
+    Ok(auction_pricing::current_price(
+        &(*price_curve).into(),
+        start_price,
+        reserve_price,
+        start_time,
+        end_time,
+        current_time,
+    )
+    .ok_or(ErrorCode::MathOverflow)?)
+}
+
+/// Uniform clearing price. Prices only decay, so the lowest fill is the last
+/// filled bid and no bidder is charged more than their own `price_per_token`.
+/// With no bids the auction clears at the price at end time.
+pub fn calculate_clearing_price(
+    participant_count: u32,
+    lowest_fill_price: u64,
+    end_price: u64,
+) -> u64 {
+    auction_pricing::clearing_price(participant_count, lowest_fill_price, end_price)
+}
+
+/// USDC owed back to a bidder who escrowed `total_cost` for `token_amount` tokens
+pub fn calculate_refund(token_amount: u64, total_cost: u64, clearing_price: u64) -> Result<u64> {
+    Ok(auction_pricing::refund(token_amount, total_cost, clearing_price)
+        .ok_or(ErrorCode::MathOverflow)?)
+}
+
+/// Uniform clearing price of a Dutch auction that has stopped taking bids: the last (lowest)
+/// filled bid, or the price at end time
+pub fn calculate_final_clearing_price(auction: &Auction) -> Result<u64> {
+    let end_price = calculate_current_price(
+        &auction.price_curve,
+        auction.start_price,
+        auction.reserve_price,
+        auction.start_time,
+        auction.curve_end_time,
+        auction.end_time,
+    )?;
+    Ok(calculate_clearing_price(
+        auction.participant_count,
+        auction.lowest_fill_price,
+        end_price,
+    ))
+}
+
+/// What the issuer keeps once the auction's sold tokens settle at `clearing_price`. This, not
+/// the gross `total_raised` (escrowed at each bid's own price), must reach `min_raise`.
+pub fn calculate_settled_proceeds(auction: &Auction, clearing_price: u64) -> Result<u64> {
+    calculate_proceeds(auction.total_tokens - auction.tokens_remaining, clearing_price)
+}
+
+/// USDC the auction authority may withdraw once every bid settles at `clearing_price`
+pub fn calculate_proceeds(tokens_sold: u64, clearing_price: u64) -> Result<u64> {
+    Ok(auction_pricing::proceeds(tokens_sold, clearing_price).ok_or(ErrorCode::MathOverflow)?)
+}
+
+// ============================================================================
+// Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(batch_number: u32)]
+pub struct CreateAuction<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [b"auction", batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Carbon-credits config; only its admin may create auctions that issue CCT
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+        constraint = cct_config.admin == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
+
+    #[account(address = cct_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Canonical USDC escrow for all bids in this auction
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = escrow_authority,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    /// USDC account that receives proceeds via `withdraw_proceeds`
+    #[account(token::mint = usdc_mint)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + Bid::INIT_SPACE,
+        seeds = [
+            b"bid",
+            auction.key().as_ref(),
+            bidder.key().as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidderPosition::INIT_SPACE,
+        seeds = [b"position", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_position: Account<'info, BidderPosition>,
+
+    /// Only registered industries can claim CCT, so only they may bid
+    #[account(
+        seeds = [b"industry", bidder.key().as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PlaceLimitOrder<'info> {
+    #[account(
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + LimitOrder::INIT_SPACE,
+        seeds = [b"limit_order", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + BidderPosition::INIT_SPACE,
+        seeds = [b"position", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub bidder_position: Account<'info, BidderPosition>,
+
+    /// Only registered industries can claim CCT, so only they may bid
+    #[account(
+        seeds = [b"industry", bidder.key().as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FillLimitOrder<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"limit_order", auction.key().as_ref(), limit_order.bidder.as_ref()],
+        bump,
+        has_one = auction,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + Bid::INIT_SPACE,
+        seeds = [
+            b"bid",
+            auction.key().as_ref(),
+            limit_order.bidder.as_ref(),
+            &Clock::get()?.unix_timestamp.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(
+        mut,
+        seeds = [b"position", auction.key().as_ref(), limit_order.bidder.as_ref()],
+        bump,
+    )]
+    pub bidder_position: Account<'info, BidderPosition>,
+
+    /// Registration of the order's owner, re-checked at fill time like `place_bid`
+    #[account(
+        seeds = [b"industry", limit_order.bidder.as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+        constraint = industry_account.authority == limit_order.bidder @ ErrorCode::Unauthorized,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLimitOrder<'info> {
+    #[account(
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"limit_order", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = auction,
+        has_one = bidder,
+        close = bidder,
+    )]
+    pub limit_order: Account<'info, LimitOrder>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: Admin authority
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimTokens<'info> {
+    #[account(
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        has_one = auction,
+        has_one = bidder,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", bidder.key().as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        address = cct_config.cct_mint,
+    )]
+    pub cct_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        associated_token::mint = cct_mint,
+        associated_token::authority = bidder,
+    )]
+    pub bidder_cct: Account<'info, TokenAccount>,
+
+    /// CHECK: Carbon-credits mint authority PDA, validated by the CPI
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Issuer PDA registered as `auction_issuer` in the carbon-credits config
+    #[account(
+        seeds = [b"cct_issuer"],
+        bump,
+    )]
+    pub issuer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub carbon_credits_program: Program<'info, CarbonCredits>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawProceeds<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+        has_one = treasury,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+    )]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: Admin authority
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+        constraint = auction.series == Pubkey::default() @ ErrorCode::SeriesBatchLocked,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// CHECK: Admin authority
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBid<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        has_one = auction,
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// Refunds can only go back to the bidder
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bid.bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(batch_number: u32)]
+pub struct CreateSealedAuction<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [b"auction", batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Revealed demand, aggregated by price
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SealedBook::INIT_SPACE,
+        seeds = [b"sealed_book", auction.key().as_ref()],
+        bump,
+    )]
+    pub sealed_book: Account<'info, SealedBook>,
+
+    /// Carbon-credits config; only its admin may create auctions that issue CCT
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+        constraint = cct_config.admin == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
+
+    #[account(address = cct_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Canonical USDC escrow for all deposits in this auction
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+        token::mint = usdc_mint,
+        token::authority = escrow_authority,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    /// USDC account that receives proceeds and forfeits
+    #[account(token::mint = usdc_mint)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitBid<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        init,
+        payer = bidder,
+        space = 8 + SealedBid::INIT_SPACE,
+        seeds = [b"sealed_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+    )]
+    pub sealed_bid: Account<'info, SealedBid>,
+
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_usdc: Account<'info, TokenAccount>,
+
+    /// Settling a sealed bid goes through the bidder's industry account, so only registered
+    /// industries may commit
+    #[account(
+        seeds = [b"industry", bidder.key().as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
 
     #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealBid<'info> {
+    #[account(
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"sealed_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = auction,
+        has_one = bidder,
+    )]
+    pub sealed_bid: Account<'info, SealedBid>,
+
+    #[account(
+        mut,
+        seeds = [b"sealed_book", auction.key().as_ref()],
+        bump,
+    )]
+    pub sealed_book: Account<'info, SealedBook>,
+
+    pub bidder: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeSealedAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        mut,
+        seeds = [b"sealed_book", auction.key().as_ref()],
+        bump,
+    )]
+    pub sealed_book: Account<'info, SealedBook>,
+
+    /// CHECK: Admin authority
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimSealedBid<'info> {
+    #[account(
+        mut,
+        seeds = [b"auction", auction.series_seed(), auction.batch_number.to_le_bytes().as_ref()],
+        bump,
+        has_one = treasury,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    #[account(
+        seeds = [b"sealed_book", auction.key().as_ref()],
+        bump,
+    )]
+    pub sealed_book: Account<'info, SealedBook>,
+
+    #[account(
+        mut,
+        seeds = [b"sealed_bid", auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        has_one = auction,
+        has_one = bidder,
+    )]
+    pub sealed_bid: Account<'info, SealedBid>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", auction.key().as_ref()],
+        bump,
+    )]
     pub escrow_usdc: Account<'info, TokenAccount>,
 
-    #[account(mut)]
-    pub bidder_usdc: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = auction.usdc_mint,
+        token::authority = bidder,
+    )]
+    pub bidder_usdc: Account<'info, TokenAccount>,
+
+    /// Receives the forfeited share of an unrevealed deposit
+    #[account(mut)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    /// CHECK: Escrow authority PDA
+    #[account(
+        seeds = [b"escrow_authority", auction.key().as_ref()],
+        bump,
+    )]
+    pub escrow_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"industry", bidder.key().as_ref()],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub industry_account: Account<'info, IndustryAccount>,
+
+    #[account(
+        mut,
+        address = cct_config.cct_mint,
+    )]
+    pub cct_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        associated_token::mint = cct_mint,
+        associated_token::authority = bidder,
+    )]
+    pub bidder_cct: Account<'info, TokenAccount>,
+
+    /// CHECK: Carbon-credits mint authority PDA, validated by the CPI
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+        seeds::program = carbon_credits::ID,
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Issuer PDA registered as `auction_issuer` in the carbon-credits config
+    #[account(
+        seeds = [b"cct_issuer"],
+        bump,
+    )]
+    pub issuer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub carbon_credits_program: Program<'info, CarbonCredits>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(series_id: u32)]
+pub struct CreateSeries<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AuctionSeries::INIT_SPACE,
+        seeds = [b"series", series_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub series: Account<'info, AuctionSeries>,
+
+    /// Carbon-credits config; only its admin may create auctions that issue CCT
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+        constraint = cct_config.admin == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
+
+    #[account(address = cct_config.usdc_mint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// USDC account that receives the proceeds of every batch
+    #[account(token::mint = usdc_mint)]
+    pub treasury: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LaunchNextBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"series", series.series_id.to_le_bytes().as_ref()],
+        bump,
+        has_one = usdc_mint,
+    )]
+    pub series: Account<'info, AuctionSeries>,
+
+    /// The series' latest batch; required once the first batch has launched
+    #[account(
+        seeds = [
+            b"auction",
+            series.key().as_ref(),
+            series.next_batch_number.wrapping_sub(1).to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub previous_auction: Option<Account<'info, Auction>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [
+            b"auction",
+            series.key().as_ref(),
+            series.next_batch_number.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub auction: Account<'info, Auction>,
+
+    /// Carbon-credits config; only its current admin may launch batches, so a series keeps
+    /// running across an admin handover
+    #[account(
+        seeds = [b"config"],
+        bump = cct_config.bump,
+        seeds::program = carbon_credits::ID,
+        constraint = cct_config.admin == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cct_config: Account<'info, ProgramConfig>,
 
-    /// CHECK: Escrow authority PDA
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Canonical USDC escrow for all bids in this auction
     #[account(
-        seeds = [b"escrow_authority", auction.key().as_ref()],
+        init,
+        payer = authority,
+        seeds = [b"escrow", auction.key().as_ref()],
         bump,
+        token::mint = usdc_mint,
+        token::authority = escrow_authority,
     )]
-    pub escrow_authority: UncheckedAccount<'info>,
-
-    pub bidder: Signer<'info>,
-    pub token_program: Program<'info, Token>,
-}
+    pub escrow_usdc: Account<'info, TokenAccount>,
 
-#[derive(Accounts)]
-pub struct CancelAuction<'info> {
+    /// CHECK: Escrow authority PDA
     #[account(
-        mut,
-        seeds = [b"auction", &auction.batch_number.to_le_bytes()],
+        seeds = [b"escrow_authority", auction.key().as_ref()],
         bump,
     )]
-    pub auction: Account<'info, Auction>,
+    pub escrow_authority: UncheckedAccount<'info>,
 
-    /// CHECK: Admin authority
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 // ============================================================================
@@ -346,16 +2415,48 @@ pub struct CancelAuction<'info> {
 pub struct Auction {
     pub authority: Pubkey,
     pub batch_number: u32,
+    /// Series that launched this batch, `Pubkey::default()` for a standalone auction
+    pub series: Pubkey,
     pub total_tokens: u64,
     pub tokens_remaining: u64,
     pub start_price: u64,
     pub current_price: u64,
     pub reserve_price: u64,
+    pub price_curve: PriceCurve,
+    pub bid_limits: BidLimits,
+    pub kind: AuctionKind,
+    pub extension: ExtensionPolicy,
+    /// USDC `total_raised` must reach by the end, or the auction fails; 0 for no minimum
+    pub min_raise: u64,
+    /// When bidding opens; a `Scheduled` auction activates once the clock passes it
     pub start_time: i64,
+    /// End of bidding, pushed out by late bids; for sealed-bid auctions the end of the commit
+    /// window
     pub end_time: i64,
+    /// When the price reaches `reserve_price`; unlike `end_time` this never moves
+    pub curve_end_time: i64,
     pub status: AuctionStatus,
     pub total_raised: u64,
     pub participant_count: u32,
+    pub usdc_mint: Pubkey,
+    pub treasury: Pubkey,
+    pub proceeds_withdrawn: u64,
+    pub lowest_fill_price: u64,
+    pub clearing_price: u64,
+    pub unsold_tokens: u64,
+}
+
+impl Auction {
+    /// Middle seed of the auction PDA. Series batches live under their series so they can
+    /// never collide with a standalone batch or another series; standalone auctions use an
+    /// empty seed, which derives the same address as `[b"auction", batch_number]`.
+    pub fn series_seed(&self) -> &[u8] {
+        if self.series == Pubkey::default() {
+            &[]
+        } else {
+            self.series.as_ref()
+        }
+    }
 }
 
 #[account]
@@ -370,25 +2471,529 @@ pub struct Bid {
     pub status: BidStatus,
 }
 
+/// Recurring batches sharing one supply budget and template
+#[account]
+#[derive(InitSpace)]
+pub struct AuctionSeries {
+    /// Admin that created the series; batches are launched by whoever is admin at the time
+    pub authority: Pubkey,
+    pub series_id: u32,
+    /// Batch number `launch_next_batch` creates next; only ever increases
+    pub next_batch_number: u32,
+    pub batches_launched: u32,
+    /// Most CCT the series may sell across all batches
+    pub supply_budget: u64,
+    /// CCT sold by settled batches
+    pub supply_sold: u64,
+    /// CCT offered per batch; the last batch offers what is left of the budget
+    pub tokens_per_batch: u64,
+    pub template: SeriesTemplate,
+    /// Open each batch this many basis points above the previous clearing price instead of at
+    /// the template start price
+    pub adaptive_start_bps: Option<u16>,
+    pub usdc_mint: Pubkey,
+    pub treasury: Pubkey,
+}
+
+/// Commitment, and once revealed the bid, of one bidder in a sealed-bid auction
+#[account]
+#[derive(InitSpace)]
+pub struct SealedBid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub commitment: [u8; 32],
+    /// USDC escrowed at commit time
+    pub deposit: u64,
+    pub revealed: bool,
+    pub token_amount: u64,
+    pub price_per_token: u64,
+    /// Counted in the sealed book; false for a reveal that found every price level taken
+    pub booked: bool,
+    pub committed_at: i64,
+    pub status: BidStatus,
+}
+
+/// Revealed demand of a sealed-bid auction, aggregated by price in descending order
+#[account]
+#[derive(InitSpace)]
+pub struct SealedBook {
+    pub auction: Pubkey,
+    #[max_len(MAX_SEALED_PRICE_LEVELS)]
+    pub levels: Vec<PriceLevel>,
+    /// Tokens demanded at the clearing price, set on finalization
+    pub marginal_demand: u64,
+    /// Tokens shared pro rata by bids at the clearing price, set on finalization
+    pub marginal_supply: u64,
+}
+
+impl SealedBook {
+    /// Whether demand at `price` fits, at an existing level or in a free one
+    pub fn has_room_for(&self, price: u64) -> bool {
+        self.levels.len() < MAX_SEALED_PRICE_LEVELS
+            || self.levels.iter().any(|level| level.price == price)
+    }
+
+    /// Add revealed demand, keeping levels sorted by descending price
+    pub fn add_demand(&mut self, price: u64, tokens: u64) -> Result<()> {
+        match self.levels.binary_search_by(|level| price.cmp(&level.price)) {
+            Ok(index) => {
+                let level = &mut self.levels[index];
+                level.tokens = level
+                    .tokens
+                    .checked_add(tokens)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+            Err(index) => {
+                require!(
+                    self.levels.len() < MAX_SEALED_PRICE_LEVELS,
+                    ErrorCode::SealedBookFull
+                );
+                self.levels.insert(index, PriceLevel { price, tokens });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clearing(&self, clearing_price: u64) -> auction_pricing::SealedClearing {
+        auction_pricing::SealedClearing {
+            clearing_price,
+            marginal_demand: self.marginal_demand,
+            marginal_supply: self.marginal_supply,
+            tokens_allocated: 0,
+        }
+    }
+}
+
+/// Running totals of one bidder in one auction, used to enforce `BidLimits`
+#[account]
+#[derive(InitSpace)]
+pub struct BidderPosition {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub tokens_bought: u64,
+    pub total_cost: u64,
+    pub bid_count: u32,
+}
+
+/// Resting order that a crank turns into a `Bid` once the price reaches `limit_price`
+#[account]
+#[derive(InitSpace)]
+pub struct LimitOrder {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub limit_price: u64,
+    /// USDC held in escrow for this order and not yet spent on a fill
+    pub deposit: u64,
+    pub status: LimitOrderStatus,
+    /// Bid created by the fill
+    pub bid: Pubkey,
+    pub created_at: i64,
+    pub filled_at: i64,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct AuctionCreated {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+    pub batch_number: u32,
+    pub total_tokens: u64,
+    pub start_price: u64,
+    pub reserve_price: u64,
+    pub price_curve: PriceCurve,
+    pub bid_limits: BidLimits,
+    pub kind: AuctionKind,
+    pub extension: ExtensionPolicy,
+    pub min_raise: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AuctionUpdated {
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    pub total_tokens: u64,
+    pub start_price: u64,
+    pub reserve_price: u64,
+    pub price_curve: PriceCurve,
+    pub bid_limits: BidLimits,
+    pub extension: ExtensionPolicy,
+    pub min_raise: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AuctionActivated {
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    pub start_time: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeriesCreated {
+    pub series: Pubkey,
+    pub authority: Pubkey,
+    pub series_id: u32,
+    pub first_batch_number: u32,
+    pub supply_budget: u64,
+    pub tokens_per_batch: u64,
+    pub template: SeriesTemplate,
+    pub adaptive_start_bps: Option<u16>,
+}
+
+#[event]
+pub struct SeriesBatchLaunched {
+    pub series: Pubkey,
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    pub total_tokens: u64,
+    pub start_price: u64,
+    pub supply_sold: u64,
+    pub supply_budget: u64,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub auction: Pubkey,
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub price_per_token: u64,
+    pub total_cost: u64,
+    pub tokens_remaining: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitOrderPlaced {
+    pub auction: Pubkey,
+    pub limit_order: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub limit_price: u64,
+    pub deposit: u64,
+}
+
+#[event]
+pub struct LimitOrderFilled {
+    pub auction: Pubkey,
+    pub limit_order: Pubkey,
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub price_per_token: u64,
+    pub cranker: Pubkey,
+}
+
+#[event]
+pub struct LimitOrderClosed {
+    pub auction: Pubkey,
+    pub limit_order: Pubkey,
+    pub bidder: Pubkey,
+    pub filled: bool,
+    pub refund: u64,
+}
+
+#[event]
+pub struct AuctionFinalized {
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    pub clearing_price: u64,
+    pub tokens_sold: u64,
+    pub unsold_tokens: u64,
+    pub total_raised: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokensClaimed {
+    pub auction: Pubkey,
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub clearing_price: u64,
+    pub refund: u64,
+}
+
+#[event]
+pub struct ProceedsWithdrawn {
+    pub auction: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub proceeds_withdrawn: u64, // Running total
+}
+
+#[event]
+pub struct SealedBidCommitted {
+    pub auction: Pubkey,
+    pub sealed_bid: Pubkey,
+    pub bidder: Pubkey,
+    pub deposit: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SealedBidRevealed {
+    pub auction: Pubkey,
+    pub sealed_bid: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub price_per_token: u64,
+    pub booked: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SealedBidSettled {
+    pub auction: Pubkey,
+    pub sealed_bid: Pubkey,
+    pub bidder: Pubkey,
+    pub revealed: bool,
+    pub tokens_won: u64,
+    pub clearing_price: u64,
+    pub refund: u64,
+    pub forfeit: u64,
+}
+
+#[event]
+pub struct AuctionExtended {
+    pub auction: Pubkey,
+    pub end_time: i64,
+    pub total_extension_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionFailed {
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    /// What the sold tokens settle for at the clearing price, not the gross deposits
+    pub total_raised: u64,
+    pub min_raise: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BidRefunded {
+    pub auction: Pubkey,
+    pub bid: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuctionCancelled {
+    pub auction: Pubkey,
+    pub batch_number: u32,
+    pub participant_count: u32,
+    pub timestamp: i64,
+}
+
 // ============================================================================
 // Enums
 // ============================================================================
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum AuctionStatus {
     Active,
     Completed,
     Finalized,
     Cancelled,
+    /// Ended below `min_raise`; every bid is refunded
+    Failed,
+    /// Announced ahead of `start_time`; opens on the first interaction after it
+    Scheduled,
+}
+
+/// Price decay from `start_price` to `reserve_price` (see `auction_pricing::Curve`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PriceCurve {
+    Linear,
+    Exponential {
+        half_life_seconds: u32,
+    },
+    Stepwise {
+        step_seconds: u32,
+        step_amount: u64,
+    },
+    PiecewiseLinear {
+        breakpoint_count: u8,
+        breakpoints: [PriceBreakpoint; MAX_PRICE_BREAKPOINTS],
+    },
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct PriceBreakpoint {
+    pub offset_seconds: u32,
+    pub price: u64,
+}
+
+/// Per-bid and per-bidder limits that keep one buyer from cornering a batch
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct BidLimits {
+    /// Smallest bid in CCT base units; the final lot of an auction may be smaller
+    pub min_bid_tokens: u64,
+    /// Most CCT one bidder may win, 0 for no limit
+    pub max_tokens_per_bidder: u64,
+    /// Most of `total_tokens` one bidder may win in basis points, 0 for no limit
+    pub max_share_bps: u16,
+}
+
+impl BidLimits {
+    pub fn is_valid(&self, total_tokens: u64) -> bool {
+        self.min_bid_tokens <= total_tokens
+            && u64::from(self.max_share_bps) <= auction_pricing::BPS_DENOMINATOR
+            && (self.max_tokens_per_bidder == 0
+                || self.max_tokens_per_bidder >= self.min_bid_tokens)
+    }
+}
+
+/// Parameters every batch of an `AuctionSeries` is created with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct SeriesTemplate {
+    pub start_price: u64,
+    pub reserve_price: u64,
+    pub duration_seconds: i64,
+    pub price_curve: PriceCurve,
+    pub bid_limits: BidLimits,
+    pub extension: ExtensionPolicy,
+    pub min_raise: u64,
+}
+
+impl SeriesTemplate {
+    pub fn is_valid(&self, tokens_per_batch: u64) -> bool {
+        self.start_price > self.reserve_price
+            && self.duration_seconds > 0
+            && auction_pricing::Curve::from(self.price_curve).is_valid(
+                self.start_price,
+                self.reserve_price,
+                self.duration_seconds,
+            )
+            && self.bid_limits.is_valid(tokens_per_batch)
+            && self.extension.is_valid(self.duration_seconds)
+    }
+}
+
+/// Anti-sniping: a bid within `extension_window_seconds` of the end keeps the auction open for
+/// at least `extension_seconds` more, up to `max_extension_seconds` past the original end.
+/// All zero (the default) disables extensions.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub struct ExtensionPolicy {
+    pub extension_window_seconds: u32,
+    pub extension_seconds: u32,
+    pub max_extension_seconds: u32,
+}
+
+impl ExtensionPolicy {
+    pub fn is_valid(&self, duration_seconds: i64) -> bool {
+        if self.extension_window_seconds == 0 {
+            return self.extension_seconds == 0 && self.max_extension_seconds == 0;
+        }
+
+        i64::from(self.extension_window_seconds) <= duration_seconds
+            && self.extension_seconds > 0
+            && self.max_extension_seconds >= self.extension_seconds
+    }
+}
+
+impl From<PriceCurve> for auction_pricing::Curve {
+    fn from(curve: PriceCurve) -> Self {
+        match curve {
+            PriceCurve::Linear => Self::Linear,
+            PriceCurve::Exponential { half_life_seconds } => {
+                Self::Exponential { half_life_seconds }
+            }
+            PriceCurve::Stepwise {
+                step_seconds,
+                step_amount,
+            } => Self::Stepwise {
+                step_seconds,
+                step_amount,
+            },
+            PriceCurve::PiecewiseLinear {
+                breakpoint_count,
+                breakpoints,
+            } => Self::PiecewiseLinear {
+                breakpoint_count,
+                breakpoints: breakpoints.map(|point| auction_pricing::Breakpoint {
+                    offset_seconds: point.offset_seconds,
+                    price: point.price,
+                }),
+            },
+        }
+    }
+}
+
+impl From<auction_pricing::Curve> for PriceCurve {
+    fn from(curve: auction_pricing::Curve) -> Self {
+        match curve {
+            auction_pricing::Curve::Linear => Self::Linear,
+            auction_pricing::Curve::Exponential { half_life_seconds } => {
+                Self::Exponential { half_life_seconds }
+            }
+            auction_pricing::Curve::Stepwise {
+                step_seconds,
+                step_amount,
+            } => Self::Stepwise {
+                step_seconds,
+                step_amount,
+            },
+            auction_pricing::Curve::PiecewiseLinear {
+                breakpoint_count,
+                breakpoints,
+            } => Self::PiecewiseLinear {
+                breakpoint_count,
+                breakpoints: breakpoints.map(|point| PriceBreakpoint {
+                    offset_seconds: point.offset_seconds,
+                    price: point.price,
+                }),
+            },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum AuctionKind {
+    /// Public descending-price auction
+    Dutch,
+    /// Commit-reveal auction at a uniform clearing price. Commitments close at `end_time`,
+    /// reveals at `reveal_end`; unrevealed deposits forfeit `forfeit_bps`.
+    SealedBid { reveal_end: i64, forfeit_bps: u16 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PriceLevel {
+    pub price: u64,
+    pub tokens: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
 pub enum BidStatus {
     Pending,
     Accepted,
     Refunded,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum LimitOrderStatus {
+    Open,
+    Filled,
+}
+
 // ============================================================================
 // Errors
 // ============================================================================
@@ -425,6 +3030,94 @@ pub enum ErrorCode {
     #[msg("Bid has already been processed")]
     BidAlreadyProcessed,
 
+    /// Unused since auctions with bids can be cancelled; kept so later error codes stay stable
     #[msg("Cannot cancel auction with participants")]
     HasParticipants,
+
+    #[msg("Signer is not authorized for this operation")]
+    Unauthorized,
+
+    #[msg("No proceeds left to withdraw")]
+    NothingToWithdraw,
+
+    #[msg("Invalid price curve for this auction")]
+    InvalidPriceCurve,
+
+    #[msg("Current price is above the bid's maximum price")]
+    PriceAboveLimit,
+
+    #[msg("Invalid limit order: amount must be positive and the limit at least the reserve price")]
+    InvalidLimitOrder,
+
+    #[msg("Limit order is not open")]
+    OrderNotOpen,
+
+    #[msg("Price has not reached the order's limit price")]
+    LimitNotReached,
+
+    #[msg("Invalid bid limits for this auction")]
+    InvalidBidLimits,
+
+    #[msg("Bid is below the auction's minimum bid size")]
+    BidBelowMinimum,
+
+    #[msg("Bid exceeds the bidder's allocation cap")]
+    BidderCapExceeded,
+
+    #[msg("Instruction does not apply to this kind of auction")]
+    WrongAuctionKind,
+
+    #[msg("Invalid forfeit: must be at most 10000 basis points")]
+    InvalidForfeit,
+
+    #[msg("Commit window has closed")]
+    CommitPhaseOver,
+
+    #[msg("Bids can only be revealed between the end of the commit window and the reveal end")]
+    NotRevealPhase,
+
+    #[msg("Bid has already been revealed")]
+    AlreadyRevealed,
+
+    #[msg("Revealed bid does not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Deposit does not cover the bid")]
+    DepositTooSmall,
+
+    #[msg("Bid price is below the reserve price")]
+    PriceBelowReserve,
+
+    #[msg("Too many distinct prices revealed in this auction")]
+    SealedBookFull,
+
+    #[msg("Invalid extension policy: window must fit the auction and extensions fit the cap")]
+    InvalidExtensionPolicy,
+
+    #[msg("Minimum raise exceeds what the auction can raise")]
+    InvalidMinRaise,
+
+    #[msg("Bids are only refunded from cancelled or failed auctions")]
+    AuctionNotRefundable,
+
+    #[msg("Auction has not started yet")]
+    AuctionNotStarted,
+
+    #[msg("Auction parameters can only change before it starts")]
+    AuctionAlreadyStarted,
+
+    #[msg("Invalid series supply: batches must be non-empty and fit the budget")]
+    InvalidSeriesSupply,
+
+    #[msg("Invalid series template")]
+    InvalidSeriesTemplate,
+
+    #[msg("Previous batch of the series has not been settled")]
+    PreviousBatchNotSettled,
+
+    #[msg("Series supply budget is used up")]
+    SeriesExhausted,
+
+    #[msg("Series batches follow their series template and cannot be updated")]
+    SeriesBatchLocked,
 }
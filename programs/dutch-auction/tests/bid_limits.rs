@@ -0,0 +1,48 @@
+use dutch_auction::{calculate_bidder_allowance, BidLimits};
+
+fn limits(min_bid_tokens: u64, max_tokens_per_bidder: u64, max_share_bps: u16) -> BidLimits {
+    BidLimits {
+        min_bid_tokens,
+        max_tokens_per_bidder,
+        max_share_bps,
+    }
+}
+
+#[test]
+fn allowance_is_unlimited_without_caps() {
+    let allowance = calculate_bidder_allowance(&limits(0, 0, 0), 1_000, 999).unwrap();
+
+    assert_eq!(allowance, u64::MAX - 999);
+}
+
+#[test]
+fn allowance_applies_the_tighter_cap() {
+    // 25% of 1,000 is tighter than the absolute cap
+    assert_eq!(
+        calculate_bidder_allowance(&limits(0, 400, 2_500), 1_000, 0).unwrap(),
+        250
+    );
+    // The absolute cap is tighter than 50%
+    assert_eq!(
+        calculate_bidder_allowance(&limits(0, 400, 5_000), 1_000, 100).unwrap(),
+        300
+    );
+}
+
+#[test]
+fn allowance_never_goes_negative() {
+    assert_eq!(
+        calculate_bidder_allowance(&limits(0, 100, 0), 1_000, 150).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn bid_limits_must_fit_the_auction() {
+    assert!(limits(0, 0, 0).is_valid(1_000));
+    assert!(limits(10, 10, 10_000).is_valid(1_000));
+
+    assert!(!limits(1_001, 0, 0).is_valid(1_000));
+    assert!(!limits(0, 0, 10_001).is_valid(1_000));
+    assert!(!limits(50, 40, 0).is_valid(1_000));
+}
@@ -0,0 +1,170 @@
+use auction_pricing::{bid_cost, CCT_UNIT};
+use dutch_auction::{
+    calculate_clearing_price, calculate_current_price, calculate_proceeds, calculate_refund,
+    PriceCurve,
+};
+use proptest::prelude::*;
+
+struct FilledBid {
+    token_amount: u64,
+    price_per_token: u64,
+    total_cost: u64,
+}
+
+struct Settlement {
+    bids: Vec<FilledBid>,
+    clearing_price: u64,
+    tokens_sold: u64,
+    total_raised: u64,
+}
+
+/// Replays `place_bid` + `finalize_auction` bookkeeping for a set of (time, amount) bids
+fn run_auction(
+    start_price: u64,
+    reserve_price: u64,
+    duration: i64,
+    total_tokens: u64,
+    mut orders: Vec<(i64, u64)>,
+) -> Settlement {
+    let start_time = 1_700_000_000;
+    let end_time = start_time + duration;
+
+    orders.sort_by_key(|(offset, _)| *offset);
+
+    let mut tokens_remaining = total_tokens;
+    let mut lowest_fill_price = start_price;
+    let mut total_raised = 0u64;
+    let mut bids = Vec::new();
+
+    for (offset, amount) in orders {
+        let now = start_time + offset;
+        let token_amount = amount.min(tokens_remaining);
+        if now >= end_time || token_amount == 0 {
+            continue;
+        }
+
+        let price = calculate_current_price(
+            &PriceCurve::Linear,
+            start_price,
+            reserve_price,
+            start_time,
+            end_time,
+            now,
+        )
+        .unwrap();
+        let total_cost = bid_cost(token_amount, price).unwrap();
+
+        tokens_remaining -= token_amount;
+        total_raised += total_cost;
+        lowest_fill_price = lowest_fill_price.min(price);
+        bids.push(FilledBid {
+            token_amount,
+            price_per_token: price,
+            total_cost,
+        });
+    }
+
+    let end_price = calculate_current_price(
+        &PriceCurve::Linear,
+        start_price,
+        reserve_price,
+        start_time,
+        end_time,
+        end_time,
+    )
+    .unwrap();
+    let clearing_price =
+        calculate_clearing_price(bids.len() as u32, lowest_fill_price, end_price);
+
+    Settlement {
+        bids,
+        clearing_price,
+        tokens_sold: total_tokens - tokens_remaining,
+        total_raised,
+    }
+}
+
+fn auction_params() -> impl Strategy<Value = (u64, u64, i64, u64, Vec<(i64, u64)>)> {
+    // Up to $2 per CCT and a million CCT
+    (1u64..1_000_000, 1u64..1_000_000, 1i64..100_000, 1u64..1_000_000 * CCT_UNIT).prop_flat_map(
+        |(reserve, spread, duration, total_tokens)| {
+            let orders = prop::collection::vec((0..duration + 10, 1u64..total_tokens + 1), 0..40);
+            (
+                Just(reserve + spread),
+                Just(reserve),
+                Just(duration),
+                Just(total_tokens),
+                orders,
+            )
+        },
+    )
+}
+
+proptest! {
+    #[test]
+    fn no_bidder_pays_more_than_their_bid_price(
+        (start_price, reserve_price, duration, total_tokens, orders) in auction_params()
+    ) {
+        let settlement = run_auction(start_price, reserve_price, duration, total_tokens, orders);
+
+        prop_assert!(settlement.clearing_price >= reserve_price);
+        for bid in &settlement.bids {
+            prop_assert!(settlement.clearing_price <= bid.price_per_token);
+
+            let refund =
+                calculate_refund(bid.token_amount, bid.total_cost, settlement.clearing_price)
+                    .unwrap();
+            prop_assert_eq!(
+                bid.total_cost - refund,
+                bid_cost(bid.token_amount, settlement.clearing_price).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn escrow_always_covers_refunds(
+        (start_price, reserve_price, duration, total_tokens, orders) in auction_params(),
+        withdraw_at in 0usize..41,
+    ) {
+        let settlement = run_auction(start_price, reserve_price, duration, total_tokens, orders);
+        let proceeds =
+            calculate_proceeds(settlement.tokens_sold, settlement.clearing_price).unwrap();
+
+        // Claims and the proceeds withdrawal may land in any order
+        let mut escrow = settlement.total_raised;
+        let withdraw_at = withdraw_at.min(settlement.bids.len());
+        for (i, bid) in settlement.bids.iter().enumerate() {
+            if i == withdraw_at {
+                escrow = escrow.checked_sub(proceeds).expect("proceeds exceed escrow");
+            }
+            let refund =
+                calculate_refund(bid.token_amount, bid.total_cost, settlement.clearing_price)
+                    .unwrap();
+            escrow = escrow.checked_sub(refund).expect("refund exceeds escrow");
+        }
+        if withdraw_at == settlement.bids.len() {
+            escrow = escrow.checked_sub(proceeds).expect("proceeds exceed escrow");
+        }
+
+        // Each bid rounds its cost up, leaving at most one base unit of dust per extra bid
+        prop_assert!(escrow < settlement.bids.len().max(1) as u64);
+    }
+}
+
+#[test]
+fn undersold_auction_clears_at_last_filled_bid() {
+    // Early bid at a high price, late bid near reserve, half the supply unsold
+    let settlement = run_auction(100, 10, 1_000, 1_000, vec![(100, 200), (900, 300)]);
+
+    assert_eq!(settlement.tokens_sold, 500);
+    assert_eq!(settlement.clearing_price, settlement.bids[1].price_per_token);
+    assert!(settlement.clearing_price > 10);
+}
+
+#[test]
+fn auction_without_bids_clears_at_end_price() {
+    let settlement = run_auction(100, 10, 1_000, 1_000, vec![]);
+
+    assert_eq!(settlement.clearing_price, 10);
+    assert_eq!(settlement.tokens_sold, 0);
+}
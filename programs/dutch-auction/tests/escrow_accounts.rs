@@ -0,0 +1,1025 @@
+//! Account validation for the auction escrow. Each test feeds a context's
+//! `try_accounts` a canonical account set with one account swapped for a
+//! spoofed one and checks the matching Anchor constraint rejects it.
+
+use std::collections::BTreeSet;
+
+use anchor_lang::error::ErrorCode as AnchorErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::ProgramResult;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use anchor_lang::solana_program::system_program;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use carbon_credits::{ComplianceStatus, IndustryAccount, ProgramConfig, VerificationStatus};
+use dutch_auction::{
+    Auction, AuctionKind, AuctionSeries, AuctionStatus, Bid, BidLimits, BidStatus, BidderPosition,
+    ClaimTokens, ClaimTokensBumps, CloseLimitOrder, CloseLimitOrderBumps, CommitBid,
+    CommitBidBumps, ExtensionPolicy, FillLimitOrder, FillLimitOrderBumps, LaunchNextBatch,
+    LaunchNextBatchBumps, LimitOrder, LimitOrderStatus, PlaceBid, PlaceBidBumps, PriceCurve,
+    RefundBid, RefundBidBumps, SealedBid, SeriesTemplate, UpdateAuction, UpdateAuctionBumps,
+    WithdrawProceeds, WithdrawProceedsBumps,
+};
+
+const NOW: i64 = 1_700_000_000;
+const BATCH: u32 = 7;
+
+/// Host stand-ins for the clock/rent sysvars and the system `create_account` CPI
+struct TestStubs;
+
+impl SyscallStubs for TestStubs {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        _signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        // SystemInstruction::CreateAccount { lamports, space, owner }
+        if instruction.program_id == system_program::ID && instruction.data[..4] == [0, 0, 0, 0] {
+            let lamports = u64::from_le_bytes(instruction.data[4..12].try_into().unwrap());
+            let owner = Pubkey::try_from(&instruction.data[20..52]).unwrap();
+            let new_account = account_infos
+                .iter()
+                .find(|info| *info.key == instruction.accounts[1].pubkey)
+                .unwrap();
+            **new_account.try_borrow_mut_lamports()? = lamports;
+            new_account.assign(&owner);
+        }
+        Ok(())
+    }
+}
+
+struct TestAccount {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+    executable: bool,
+}
+
+impl TestAccount {
+    fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+        Self {
+            key,
+            owner,
+            lamports: 1_000_000_000,
+            data,
+            is_signer: false,
+            is_writable: true,
+            executable: false,
+        }
+    }
+
+    fn signer(key: Pubkey) -> Self {
+        Self {
+            is_signer: true,
+            ..Self::new(key, system_program::ID, vec![])
+        }
+    }
+
+    fn program(key: Pubkey) -> Self {
+        Self {
+            is_writable: false,
+            executable: true,
+            ..Self::new(key, Pubkey::default(), vec![])
+        }
+    }
+
+    /// Uninitialized system account waiting for an `init` constraint
+    fn empty(key: Pubkey, space: usize) -> Self {
+        Self {
+            lamports: 0,
+            ..Self::new(key, system_program::ID, vec![0; space])
+        }
+    }
+
+    fn anchor<T: AccountSerialize>(key: Pubkey, owner: Pubkey, state: &T) -> Self {
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        Self::new(key, owner, data)
+    }
+
+    fn token(key: Pubkey, mint: Pubkey, owner: Pubkey) -> Self {
+        let state = spl_token::state::Account {
+            mint,
+            owner,
+            amount: 1_000_000_000,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        state.pack_into_slice(&mut data);
+        Self::new(key, spl_token::ID, data)
+    }
+
+    fn mint(key: Pubkey, decimals: u8) -> Self {
+        let state = spl_token::state::Mint {
+            decimals,
+            is_initialized: true,
+            ..Default::default()
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        state.pack_into_slice(&mut data);
+        Self::new(key, spl_token::ID, data)
+    }
+
+    fn info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(
+            &self.key,
+            self.is_signer,
+            self.is_writable,
+            &mut self.lamports,
+            &mut self.data,
+            &self.owner,
+            self.executable,
+            0,
+        )
+    }
+}
+
+fn pda(seeds: &[&[u8]], program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, program_id)
+}
+
+fn auction_key(batch_number: u32) -> Pubkey {
+    pda(&[b"auction", &batch_number.to_le_bytes()], &dutch_auction::ID).0
+}
+
+fn series_auction_key(series: &Pubkey, batch_number: u32) -> Pubkey {
+    pda(&[b"auction", series.as_ref(), &batch_number.to_le_bytes()], &dutch_auction::ID).0
+}
+
+fn limit_order_key(auction: &Pubkey, bidder: &Pubkey) -> Pubkey {
+    pda(&[b"limit_order", auction.as_ref(), bidder.as_ref()], &dutch_auction::ID).0
+}
+
+fn position_key(auction: &Pubkey, bidder: &Pubkey) -> Pubkey {
+    pda(&[b"position", auction.as_ref(), bidder.as_ref()], &dutch_auction::ID).0
+}
+
+fn industry_key(authority: &Pubkey) -> Pubkey {
+    pda(&[b"industry", authority.as_ref()], &carbon_credits::ID).0
+}
+
+fn escrow_key(auction: &Pubkey) -> Pubkey {
+    pda(&[b"escrow", auction.as_ref()], &dutch_auction::ID).0
+}
+
+fn escrow_authority_key(auction: &Pubkey) -> Pubkey {
+    pda(&[b"escrow_authority", auction.as_ref()], &dutch_auction::ID).0
+}
+
+/// Keys shared by every fixture
+struct World {
+    authority: Pubkey,
+    bidder: Pubkey,
+    usdc_mint: Pubkey,
+    cct_mint: Pubkey,
+    treasury: Pubkey,
+}
+
+impl World {
+    fn new() -> Self {
+        set_syscall_stubs(Box::new(TestStubs));
+        Self {
+            authority: Pubkey::new_unique(),
+            bidder: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            cct_mint: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+        }
+    }
+
+    fn auction(&self, status: AuctionStatus) -> Auction {
+        Auction {
+            authority: self.authority,
+            batch_number: BATCH,
+            series: Pubkey::default(),
+            total_tokens: 1_000,
+            tokens_remaining: 900,
+            start_price: 100,
+            current_price: 50,
+            reserve_price: 10,
+            price_curve: PriceCurve::Linear,
+            bid_limits: BidLimits::default(),
+            kind: AuctionKind::Dutch,
+            extension: ExtensionPolicy::default(),
+            min_raise: 0,
+            start_time: NOW - 100,
+            end_time: NOW + 100,
+            curve_end_time: NOW + 100,
+            status,
+            total_raised: 5_000,
+            participant_count: 1,
+            usdc_mint: self.usdc_mint,
+            treasury: self.treasury,
+            proceeds_withdrawn: 0,
+            lowest_fill_price: 50,
+            clearing_price: 50,
+            unsold_tokens: 0,
+        }
+    }
+
+    fn auction_account(&self, status: AuctionStatus) -> TestAccount {
+        TestAccount::anchor(auction_key(BATCH), dutch_auction::ID, &self.auction(status))
+    }
+
+    fn escrow_account(&self) -> TestAccount {
+        let auction = auction_key(BATCH);
+        TestAccount::token(
+            escrow_key(&auction),
+            self.usdc_mint,
+            escrow_authority_key(&auction),
+        )
+    }
+
+    fn escrow_authority_account(&self) -> TestAccount {
+        let key = escrow_authority_key(&auction_key(BATCH));
+        TestAccount::new(key, system_program::ID, vec![])
+    }
+
+    /// The bidder's verified carbon-credits registration
+    fn industry_account(&self) -> TestAccount {
+        let industry = IndustryAccount {
+            authority: self.bidder,
+            company_name: "Acme".to_string(),
+            bond_amount: 0,
+            verification_status: VerificationStatus::Verified,
+            status_reason: 0,
+            status_updated_at: NOW,
+            cct_balance: 0,
+            total_purchased: 0,
+            total_burned: 0,
+            compliance_status: ComplianceStatus::Compliant,
+            created_at: NOW,
+            non_compliant_since: 0,
+            total_slashed: 0,
+            slash_count: 0,
+            last_slashed_at: 0,
+            credential_mint: Pubkey::new_unique(),
+            credential_count: 1,
+            verified_by: self.authority,
+            verified_at: NOW,
+        };
+        TestAccount::anchor(industry_key(&self.bidder), carbon_credits::ID, &industry)
+    }
+
+    fn place_bid_accounts(&self) -> Vec<TestAccount> {
+        let auction = auction_key(BATCH);
+        let (bid, _) = pda(
+            &[b"bid", auction.as_ref(), self.bidder.as_ref(), &NOW.to_le_bytes()],
+            &dutch_auction::ID,
+        );
+        vec![
+            self.auction_account(AuctionStatus::Active),
+            TestAccount::empty(bid, 8 + Bid::INIT_SPACE),
+            TestAccount::token(Pubkey::new_unique(), self.usdc_mint, self.bidder),
+            self.escrow_account(),
+            TestAccount::empty(
+                position_key(&auction, &self.bidder),
+                8 + BidderPosition::INIT_SPACE,
+            ),
+            self.industry_account(),
+            TestAccount::signer(self.bidder),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(system_program::ID),
+        ]
+    }
+
+    fn commit_bid_accounts(&self) -> Vec<TestAccount> {
+        let auction = auction_key(BATCH);
+        let mut sealed = self.auction(AuctionStatus::Active);
+        sealed.kind = AuctionKind::SealedBid {
+            reveal_end: NOW + 200,
+            forfeit_bps: 1_000,
+        };
+        let (sealed_bid, _) = pda(
+            &[b"sealed_bid", auction.as_ref(), self.bidder.as_ref()],
+            &dutch_auction::ID,
+        );
+
+        vec![
+            TestAccount::anchor(auction, dutch_auction::ID, &sealed),
+            TestAccount::empty(sealed_bid, 8 + SealedBid::INIT_SPACE),
+            TestAccount::token(Pubkey::new_unique(), self.usdc_mint, self.bidder),
+            self.escrow_account(),
+            self.industry_account(),
+            TestAccount::signer(self.bidder),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(system_program::ID),
+        ]
+    }
+
+    /// Carbon-credits config with `self.authority` as admin
+    fn cct_config_account(&self) -> TestAccount {
+        let (config, config_bump) = pda(&[b"config"], &carbon_credits::ID);
+        let cct_config = ProgramConfig {
+            admin: self.authority,
+            pending_admin: None,
+            verifiers: vec![],
+            cct_mint: self.cct_mint,
+            usdc_mint: self.usdc_mint,
+            treasury: Pubkey::new_unique(),
+            auction_issuer: pda(&[b"cct_issuer"], &dutch_auction::ID).0,
+            penalty_treasury: Pubkey::new_unique(),
+            min_bond: 0,
+            cct_price: 1,
+            slash_bps: 0,
+            slash_grace_period: 0,
+            bump: config_bump,
+        };
+        TestAccount::anchor(config, carbon_credits::ID, &cct_config)
+    }
+
+    fn claim_tokens_accounts(&self) -> Vec<TestAccount> {
+        let auction = auction_key(BATCH);
+        let (mint_authority, _) = pda(&[b"mint_authority"], &carbon_credits::ID);
+        let (issuer, _) = pda(&[b"cct_issuer"], &dutch_auction::ID);
+
+        let bid = Bid {
+            auction,
+            bidder: self.bidder,
+            token_amount: 100,
+            price_per_token: 50,
+            total_cost: 5_000,
+            timestamp: NOW - 50,
+            status: BidStatus::Pending,
+        };
+
+        vec![
+            self.auction_account(AuctionStatus::Finalized),
+            TestAccount::anchor(Pubkey::new_unique(), dutch_auction::ID, &bid),
+            self.escrow_account(),
+            TestAccount::token(Pubkey::new_unique(), self.usdc_mint, self.bidder),
+            self.escrow_authority_account(),
+            self.cct_config_account(),
+            self.industry_account(),
+            TestAccount::mint(self.cct_mint, 9),
+            TestAccount::token(
+                get_associated_token_address(&self.bidder, &self.cct_mint),
+                self.cct_mint,
+                self.bidder,
+            ),
+            TestAccount::new(mint_authority, system_program::ID, vec![]),
+            TestAccount::new(issuer, system_program::ID, vec![]),
+            TestAccount::signer(self.bidder),
+            TestAccount::program(carbon_credits::ID),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(anchor_spl::associated_token::ID),
+            TestAccount::program(system_program::ID),
+        ]
+    }
+
+    /// First batch of a series created by `creator`, launched by `signer`
+    fn launch_next_batch_accounts(&self, creator: Pubkey, signer: Pubkey) -> Vec<TestAccount> {
+        let (series, _) = pda(&[b"series", &1u32.to_le_bytes()], &dutch_auction::ID);
+        let auction = series_auction_key(&series, BATCH);
+        let state = AuctionSeries {
+            authority: creator,
+            series_id: 1,
+            next_batch_number: BATCH,
+            batches_launched: 0,
+            supply_budget: 10_000,
+            supply_sold: 0,
+            tokens_per_batch: 1_000,
+            template: SeriesTemplate {
+                start_price: 100,
+                reserve_price: 10,
+                duration_seconds: 3_600,
+                price_curve: PriceCurve::Linear,
+                bid_limits: BidLimits::default(),
+                extension: ExtensionPolicy::default(),
+                min_raise: 0,
+            },
+            adaptive_start_bps: None,
+            usdc_mint: self.usdc_mint,
+            treasury: self.treasury,
+        };
+        // `init` leaves the data as found, so the escrow already holds its post-init state
+        let escrow = TestAccount {
+            lamports: 0,
+            owner: system_program::ID,
+            ..TestAccount::token(
+                escrow_key(&auction),
+                self.usdc_mint,
+                escrow_authority_key(&auction),
+            )
+        };
+
+        vec![
+            TestAccount::anchor(series, dutch_auction::ID, &state),
+            // No previous batch: optional accounts are passed as the program id
+            TestAccount::program(dutch_auction::ID),
+            TestAccount::empty(auction, 8 + Auction::INIT_SPACE),
+            self.cct_config_account(),
+            TestAccount::mint(self.usdc_mint, 6),
+            escrow,
+            TestAccount::new(escrow_authority_key(&auction), system_program::ID, vec![]),
+            TestAccount::signer(signer),
+            TestAccount::program(spl_token::ID),
+            TestAccount::program(system_program::ID),
+        ]
+    }
+
+    fn limit_order(&self, auction: Pubkey, status: LimitOrderStatus) -> LimitOrder {
+        LimitOrder {
+            auction,
+            bidder: self.bidder,
+            token_amount: 100,
+            limit_price: 60,
+            deposit: 6_000,
+            status,
+            bid: Pubkey::default(),
+            created_at: NOW - 50,
+            filled_at: 0,
+        }
+    }
+
+    fn limit_order_account(&self, status: LimitOrderStatus) -> TestAccount {
+        let auction = auction_key(BATCH);
+        TestAccount::anchor(
+            limit_order_key(&auction, &self.bidder),
+            dutch_auction::ID,
+            &self.limit_order(auction, status),
+        )
+    }
+
+    fn fill_limit_order_accounts(&self) -> Vec<TestAccount> {
+        let auction = auction_key(BATCH);
+        let (bid, _) = pda(
+            &[b"bid", auction.as_ref(), self.bidder.as_ref(), &NOW.to_le_bytes()],
+            &dutch_auction::ID,
+        );
+        vec![
+            self.auction_account(AuctionStatus::Active),
+            self.limit_order_account(LimitOrderStatus::Open),
+            TestAccount::empty(bid, 8 + Bid::INIT_SPACE),
+            TestAccount::anchor(
+                position_key(&auction, &self.bidder),
+                dutch_auction::ID,
+                &BidderPosition {
+                    auction,
+                    bidder: self.bidder,
+                    tokens_bought: 0,
+                    total_cost: 0,
+                    bid_count: 0,
+                },
+            ),
+            self.industry_account(),
+            TestAccount::signer(Pubkey::new_unique()),
+            TestAccount::program(system_program::ID),
+        ]
+    }
+
+    fn close_limit_order_accounts(&self) -> Vec<TestAccount> {
+        vec![
+            self.auction_account(AuctionStatus::Active),
+            self.limit_order_account(LimitOrderStatus::Filled),
+            self.escrow_account(),
+            TestAccount::token(Pubkey::new_unique(), self.usdc_mint, self.bidder),
+            self.escrow_authority_account(),
+            TestAccount::signer(self.bidder),
+            TestAccount::program(spl_token::ID),
+        ]
+    }
+
+    fn refund_bid_accounts(&self) -> Vec<TestAccount> {
+        let bid = Bid {
+            auction: auction_key(BATCH),
+            bidder: self.bidder,
+            token_amount: 100,
+            price_per_token: 50,
+            total_cost: 5_000,
+            timestamp: NOW - 50,
+            status: BidStatus::Pending,
+        };
+
+        vec![
+            self.auction_account(AuctionStatus::Cancelled),
+            TestAccount::anchor(Pubkey::new_unique(), dutch_auction::ID, &bid),
+            self.escrow_account(),
+            TestAccount::token(Pubkey::new_unique(), self.usdc_mint, self.bidder),
+            self.escrow_authority_account(),
+            TestAccount::program(spl_token::ID),
+        ]
+    }
+
+    fn withdraw_proceeds_accounts(&self) -> Vec<TestAccount> {
+        vec![
+            self.auction_account(AuctionStatus::Finalized),
+            self.escrow_account(),
+            TestAccount::token(self.treasury, self.usdc_mint, self.authority),
+            self.escrow_authority_account(),
+            TestAccount::signer(self.authority),
+            TestAccount::program(spl_token::ID),
+        ]
+    }
+}
+
+fn place_bid(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    PlaceBid::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut PlaceBidBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn commit_bid(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    CommitBid::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut CommitBidBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn claim_tokens(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    ClaimTokens::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut ClaimTokensBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn fill_limit_order(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    FillLimitOrder::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut FillLimitOrderBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn close_limit_order(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    CloseLimitOrder::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut CloseLimitOrderBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn refund_bid(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    RefundBid::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut RefundBidBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn withdraw_proceeds(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    WithdrawProceeds::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut WithdrawProceedsBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+/// `expected` is an Anchor constraint error or a `dutch_auction::ErrorCode`
+fn launch_next_batch(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    LaunchNextBatch::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut LaunchNextBatchBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+fn update_auction(accounts: &mut [TestAccount]) -> Result<()> {
+    let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+    UpdateAuction::try_accounts(
+        &dutch_auction::ID,
+        &mut infos.as_slice(),
+        &[],
+        &mut UpdateAuctionBumps::default(),
+        &mut BTreeSet::new(),
+    )
+    .map(|_| ())
+}
+
+/// `expected` is an Anchor constraint error or a `dutch_auction::ErrorCode`
+fn assert_rejected<E: Into<u32> + Copy + std::fmt::Debug>(result: Result<()>, expected: E) {
+    match result {
+        Err(Error::AnchorError(err)) => assert_eq!(
+            err.error_code_number,
+            expected.into(),
+            "unexpected error: {}",
+            err
+        ),
+        Err(err) => panic!("expected {:?}, got {:?}", expected, err),
+        Ok(()) => panic!("expected {:?}, accounts were accepted", expected),
+    }
+}
+
+// place_bid: accounts [auction, bid, bidder_usdc, escrow_usdc, position, industry, bidder, ..]
+
+#[test]
+fn place_bid_accepts_canonical_escrow() {
+    let world = World::new();
+    place_bid(&mut world.place_bid_accounts()).unwrap();
+}
+
+#[test]
+fn place_bid_rejects_bidder_owned_escrow() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    accounts[3] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, world.bidder);
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn place_bid_rejects_escrow_of_another_auction() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    let other = auction_key(BATCH + 1);
+    accounts[3] = TestAccount::token(
+        escrow_key(&other),
+        world.usdc_mint,
+        escrow_authority_key(&other),
+    );
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn place_bid_rejects_payment_in_foreign_mint() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    accounts[2] = TestAccount::token(Pubkey::new_unique(), Pubkey::new_unique(), world.bidder);
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::ConstraintTokenMint);
+}
+
+#[test]
+fn place_bid_rejects_payment_from_someone_elses_account() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    accounts[2] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, Pubkey::new_unique());
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::ConstraintTokenOwner);
+}
+
+#[test]
+fn place_bid_rejects_bidder_without_industry_account() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    accounts[5] = TestAccount::empty(industry_key(&world.bidder), 0);
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::AccountNotInitialized);
+}
+
+#[test]
+fn place_bid_rejects_another_industrys_registration() {
+    let world = World::new();
+    let mut accounts = world.place_bid_accounts();
+    let other = World::new();
+    accounts[5] = other.industry_account();
+
+    assert_rejected(place_bid(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+// commit_bid: accounts [auction, sealed_bid, bidder_usdc, escrow_usdc, industry, bidder, ..]
+
+#[test]
+fn commit_bid_accepts_a_registered_industry() {
+    let world = World::new();
+    commit_bid(&mut world.commit_bid_accounts()).unwrap();
+}
+
+#[test]
+fn commit_bid_rejects_bidder_without_industry_account() {
+    let world = World::new();
+    let mut accounts = world.commit_bid_accounts();
+    accounts[4] = TestAccount::empty(industry_key(&world.bidder), 0);
+
+    assert_rejected(commit_bid(&mut accounts), AnchorErrorCode::AccountNotInitialized);
+}
+
+// claim_tokens: accounts [auction, bid, escrow_usdc, bidder_usdc, escrow_authority, ..]
+
+#[test]
+fn claim_tokens_accepts_canonical_escrow() {
+    let world = World::new();
+    claim_tokens(&mut world.claim_tokens_accounts()).unwrap();
+}
+
+#[test]
+fn claim_tokens_rejects_spoofed_escrow() {
+    let world = World::new();
+    let mut accounts = world.claim_tokens_accounts();
+    accounts[2] = TestAccount::token(
+        Pubkey::new_unique(),
+        world.usdc_mint,
+        escrow_authority_key(&auction_key(BATCH)),
+    );
+
+    assert_rejected(claim_tokens(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn claim_tokens_rejects_refund_to_foreign_mint() {
+    let world = World::new();
+    let mut accounts = world.claim_tokens_accounts();
+    accounts[3] = TestAccount::token(Pubkey::new_unique(), Pubkey::new_unique(), world.bidder);
+
+    assert_rejected(claim_tokens(&mut accounts), AnchorErrorCode::ConstraintTokenMint);
+}
+
+#[test]
+fn claim_tokens_rejects_refund_to_someone_elses_account() {
+    let world = World::new();
+    let mut accounts = world.claim_tokens_accounts();
+    accounts[3] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, Pubkey::new_unique());
+
+    assert_rejected(claim_tokens(&mut accounts), AnchorErrorCode::ConstraintTokenOwner);
+}
+
+#[test]
+fn claim_tokens_rejects_bid_from_another_auction() {
+    let world = World::new();
+    let mut accounts = world.claim_tokens_accounts();
+    let bid = Bid {
+        auction: auction_key(BATCH + 1),
+        bidder: world.bidder,
+        token_amount: 100,
+        price_per_token: 50,
+        total_cost: 5_000,
+        timestamp: NOW - 50,
+        status: BidStatus::Pending,
+    };
+    accounts[1] = TestAccount::anchor(Pubkey::new_unique(), dutch_auction::ID, &bid);
+
+    assert_rejected(claim_tokens(&mut accounts), AnchorErrorCode::ConstraintHasOne);
+}
+
+// fill_limit_order: accounts [auction, limit_order, bid, bidder_position, industry, cranker, ..]
+
+#[test]
+fn fill_limit_order_accepts_any_cranker() {
+    let world = World::new();
+    fill_limit_order(&mut world.fill_limit_order_accounts()).unwrap();
+}
+
+#[test]
+fn fill_limit_order_rejects_order_from_another_auction() {
+    let world = World::new();
+    let mut accounts = world.fill_limit_order_accounts();
+    let other = auction_key(BATCH + 1);
+    accounts[1] = TestAccount::anchor(
+        limit_order_key(&other, &world.bidder),
+        dutch_auction::ID,
+        &world.limit_order(other, LimitOrderStatus::Open),
+    );
+
+    assert_rejected(fill_limit_order(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn fill_limit_order_rejects_another_bidders_position() {
+    let world = World::new();
+    let mut accounts = world.fill_limit_order_accounts();
+    let auction = auction_key(BATCH);
+    let other = Pubkey::new_unique();
+    accounts[3] = TestAccount::anchor(
+        position_key(&auction, &other),
+        dutch_auction::ID,
+        &BidderPosition {
+            auction,
+            bidder: other,
+            tokens_bought: 0,
+            total_cost: 0,
+            bid_count: 0,
+        },
+    );
+
+    assert_rejected(fill_limit_order(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn fill_limit_order_rejects_another_industrys_registration() {
+    let world = World::new();
+    let mut accounts = world.fill_limit_order_accounts();
+    let other = World::new();
+    accounts[4] = other.industry_account();
+
+    assert_rejected(fill_limit_order(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn fill_limit_order_rejects_registration_of_another_authority() {
+    let world = World::new();
+    let mut accounts = world.fill_limit_order_accounts();
+    // Right address, but the registration names someone else
+    let industry = world.industry_account();
+    let mut state = IndustryAccount::try_deserialize(&mut industry.data.as_slice()).unwrap();
+    state.authority = Pubkey::new_unique();
+    accounts[4] = TestAccount::anchor(industry.key, carbon_credits::ID, &state);
+
+    assert_rejected(fill_limit_order(&mut accounts), dutch_auction::ErrorCode::Unauthorized);
+}
+
+// close_limit_order: accounts [auction, limit_order, escrow_usdc, bidder_usdc, ..]
+
+#[test]
+fn close_limit_order_accepts_canonical_escrow() {
+    let world = World::new();
+    close_limit_order(&mut world.close_limit_order_accounts()).unwrap();
+}
+
+#[test]
+fn close_limit_order_rejects_spoofed_escrow() {
+    let world = World::new();
+    let mut accounts = world.close_limit_order_accounts();
+    accounts[2] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, world.bidder);
+
+    assert_rejected(close_limit_order(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn close_limit_order_rejects_someone_elses_order() {
+    let world = World::new();
+    let mut accounts = world.close_limit_order_accounts();
+    let thief = Pubkey::new_unique();
+    accounts[3] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, thief);
+    accounts[5] = TestAccount::signer(thief);
+
+    assert_rejected(close_limit_order(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+// refund_bid: accounts [auction, bid, escrow_usdc, bidder_usdc, escrow_authority, ..]
+
+#[test]
+fn refund_bid_accepts_any_sender() {
+    let world = World::new();
+    refund_bid(&mut world.refund_bid_accounts()).unwrap();
+}
+
+#[test]
+fn refund_bid_rejects_spoofed_escrow() {
+    let world = World::new();
+    let mut accounts = world.refund_bid_accounts();
+    accounts[2] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, world.bidder);
+
+    assert_rejected(refund_bid(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn refund_bid_rejects_refund_to_someone_elses_account() {
+    let world = World::new();
+    let mut accounts = world.refund_bid_accounts();
+    accounts[3] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, Pubkey::new_unique());
+
+    assert_rejected(refund_bid(&mut accounts), AnchorErrorCode::ConstraintTokenOwner);
+}
+
+// withdraw_proceeds: accounts [auction, escrow_usdc, treasury, escrow_authority, authority, ..]
+
+#[test]
+fn withdraw_proceeds_accepts_canonical_escrow() {
+    let world = World::new();
+    withdraw_proceeds(&mut world.withdraw_proceeds_accounts()).unwrap();
+}
+
+#[test]
+fn withdraw_proceeds_rejects_spoofed_escrow() {
+    let world = World::new();
+    let mut accounts = world.withdraw_proceeds_accounts();
+    accounts[1] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, world.authority);
+
+    assert_rejected(withdraw_proceeds(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn withdraw_proceeds_rejects_substituted_treasury() {
+    let world = World::new();
+    let mut accounts = world.withdraw_proceeds_accounts();
+    accounts[2] = TestAccount::token(Pubkey::new_unique(), world.usdc_mint, world.authority);
+
+    assert_rejected(withdraw_proceeds(&mut accounts), AnchorErrorCode::ConstraintHasOne);
+}
+
+#[test]
+fn withdraw_proceeds_accepts_a_series_batch() {
+    let world = World::new();
+    let series = Pubkey::new_unique();
+    let auction = series_auction_key(&series, BATCH);
+    let mut state = world.auction(AuctionStatus::Finalized);
+    state.series = series;
+
+    let mut accounts = world.withdraw_proceeds_accounts();
+    accounts[0] = TestAccount::anchor(auction, dutch_auction::ID, &state);
+    accounts[1] = TestAccount::token(
+        escrow_key(&auction),
+        world.usdc_mint,
+        escrow_authority_key(&auction),
+    );
+    accounts[3] = TestAccount::new(escrow_authority_key(&auction), system_program::ID, vec![]);
+
+    withdraw_proceeds(&mut accounts).unwrap();
+}
+
+#[test]
+fn withdraw_proceeds_rejects_series_batch_at_a_standalone_address() {
+    let world = World::new();
+    let mut state = world.auction(AuctionStatus::Finalized);
+    state.series = Pubkey::new_unique();
+
+    let mut accounts = world.withdraw_proceeds_accounts();
+    accounts[0] = TestAccount::anchor(auction_key(BATCH), dutch_auction::ID, &state);
+
+    assert_rejected(withdraw_proceeds(&mut accounts), AnchorErrorCode::ConstraintSeeds);
+}
+
+// update_auction: accounts [auction, authority]
+
+#[test]
+fn update_auction_accepts_a_scheduled_standalone_auction() {
+    let world = World::new();
+    let mut accounts = vec![
+        world.auction_account(AuctionStatus::Scheduled),
+        TestAccount::signer(world.authority),
+    ];
+
+    update_auction(&mut accounts).unwrap();
+}
+
+#[test]
+fn update_auction_rejects_a_series_batch() {
+    let world = World::new();
+    let series = Pubkey::new_unique();
+    let mut state = world.auction(AuctionStatus::Scheduled);
+    state.series = series;
+    let mut accounts = vec![
+        TestAccount::anchor(series_auction_key(&series, BATCH), dutch_auction::ID, &state),
+        TestAccount::signer(world.authority),
+    ];
+
+    assert_rejected(update_auction(&mut accounts), dutch_auction::ErrorCode::SeriesBatchLocked);
+}
+
+// launch_next_batch: accounts [series, previous_auction, auction, cct_config, usdc_mint, ..]
+
+#[test]
+fn launch_next_batch_accepts_the_admin_after_a_handover() {
+    let world = World::new();
+    // The series was created by the previous admin; `world.authority` is admin now
+    let mut accounts = world.launch_next_batch_accounts(Pubkey::new_unique(), world.authority);
+
+    launch_next_batch(&mut accounts).unwrap();
+}
+
+#[test]
+fn launch_next_batch_rejects_a_former_admin() {
+    let world = World::new();
+    let former_admin = Pubkey::new_unique();
+    let mut accounts = world.launch_next_batch_accounts(former_admin, former_admin);
+
+    assert_rejected(launch_next_batch(&mut accounts), dutch_auction::ErrorCode::Unauthorized);
+}
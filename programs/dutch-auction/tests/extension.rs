@@ -0,0 +1,80 @@
+use auction_pricing::extended_end_time;
+use dutch_auction::{calculate_current_price, ExtensionPolicy, PriceCurve};
+
+const CURVE_END: i64 = 3_600;
+
+fn extension_policy(window: u32, extension: u32, max_extension: u32) -> ExtensionPolicy {
+    ExtensionPolicy {
+        extension_window_seconds: window,
+        extension_seconds: extension,
+        max_extension_seconds: max_extension,
+    }
+}
+
+fn end_after_bid(policy: &ExtensionPolicy, end_time: i64, now: i64) -> i64 {
+    extended_end_time(
+        CURVE_END,
+        end_time,
+        now,
+        policy.extension_window_seconds,
+        policy.extension_seconds,
+        policy.max_extension_seconds,
+    )
+    .unwrap()
+}
+
+#[test]
+fn bids_outside_the_window_leave_the_end_alone() {
+    let policy = extension_policy(60, 120, 600);
+
+    assert_eq!(end_after_bid(&policy, CURVE_END, 0), CURVE_END);
+    assert_eq!(end_after_bid(&policy, CURVE_END, CURVE_END - 61), CURVE_END);
+    // Disabled policy never extends
+    assert_eq!(end_after_bid(&extension_policy(0, 0, 0), CURVE_END, CURVE_END - 1), CURVE_END);
+}
+
+#[test]
+fn late_bids_extend_up_to_the_cap() {
+    let policy = extension_policy(60, 120, 300);
+
+    // Bid 10s before the end keeps the auction open 120s from the bid
+    let end = end_after_bid(&policy, CURVE_END, CURVE_END - 10);
+    assert_eq!(end, CURVE_END + 110);
+
+    // A bid in the extended window pushes it further, never shortening it
+    let end = end_after_bid(&policy, end, end - 30);
+    assert_eq!(end, CURVE_END + 200);
+    assert_eq!(end_after_bid(&policy, end, end - 60), end + 60);
+
+    // Total extension stops at max_extension_seconds
+    let end = end_after_bid(&policy, CURVE_END + 280, CURVE_END + 279);
+    assert_eq!(end, CURVE_END + 300);
+    assert_eq!(end_after_bid(&policy, end, end - 1), CURVE_END + 300);
+}
+
+#[test]
+fn price_holds_at_reserve_while_extended() {
+    for curve in [
+        PriceCurve::Linear,
+        PriceCurve::Exponential {
+            half_life_seconds: 600,
+        },
+    ] {
+        let price = |now| calculate_current_price(&curve, 100, 10, 0, CURVE_END, now).unwrap();
+
+        assert!(price(CURVE_END - 1) > 10);
+        assert_eq!(price(CURVE_END), 10);
+        assert_eq!(price(CURVE_END + 300), 10);
+    }
+}
+
+#[test]
+fn extension_policy_must_fit_the_auction() {
+    assert!(extension_policy(0, 0, 0).is_valid(3_600));
+    assert!(extension_policy(60, 120, 120).is_valid(3_600));
+
+    assert!(!extension_policy(0, 120, 600).is_valid(3_600));
+    assert!(!extension_policy(60, 0, 600).is_valid(3_600));
+    assert!(!extension_policy(60, 120, 60).is_valid(3_600));
+    assert!(!extension_policy(3_601, 120, 600).is_valid(3_600));
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::Pubkey;
+use auction_pricing::{bid_cost, CCT_UNIT};
+use dutch_auction::{
+    calculate_final_clearing_price, calculate_settled_proceeds, Auction, AuctionKind,
+    AuctionStatus, BidLimits, ExtensionPolicy, PriceCurve,
+};
+
+const NOW: i64 = 1_700_000_000;
+
+/// 10 CCT on a linear curve from 100 to 10 USDC that ended at `NOW`
+fn ended_auction() -> Auction {
+    Auction {
+        authority: Pubkey::new_unique(),
+        batch_number: 1,
+        series: Pubkey::default(),
+        total_tokens: 10 * CCT_UNIT,
+        tokens_remaining: 10 * CCT_UNIT,
+        start_price: 100,
+        current_price: 10,
+        reserve_price: 10,
+        price_curve: PriceCurve::Linear,
+        bid_limits: BidLimits::default(),
+        kind: AuctionKind::Dutch,
+        extension: ExtensionPolicy::default(),
+        min_raise: 0,
+        start_time: NOW - 3_600,
+        end_time: NOW,
+        curve_end_time: NOW,
+        status: AuctionStatus::Active,
+        total_raised: 0,
+        participant_count: 0,
+        usdc_mint: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        proceeds_withdrawn: 0,
+        lowest_fill_price: 100,
+        clearing_price: 0,
+        unsold_tokens: 0,
+    }
+}
+
+fn fill(auction: &mut Auction, token_amount: u64, price: u64) {
+    auction.tokens_remaining -= token_amount;
+    auction.total_raised += bid_cost(token_amount, price).unwrap();
+    auction.participant_count += 1;
+    auction.lowest_fill_price = auction.lowest_fill_price.min(price);
+}
+
+#[test]
+fn floor_is_checked_against_settled_proceeds_not_gross_deposits() {
+    let mut auction = ended_auction();
+    auction.min_raise = 150;
+    fill(&mut auction, CCT_UNIT, 100);
+    fill(&mut auction, 9 * CCT_UNIT, 10);
+
+    // 100 + 90 USDC escrowed clears the floor...
+    assert_eq!(auction.total_raised, 190);
+    // ...but every CCT settles at 10, so the issuer only keeps 100
+    let clearing_price = calculate_final_clearing_price(&auction).unwrap();
+    assert_eq!(clearing_price, 10);
+    let settled = calculate_settled_proceeds(&auction, clearing_price).unwrap();
+    assert_eq!(settled, 100);
+    assert!(settled < auction.min_raise);
+}
+
+#[test]
+fn settled_proceeds_meet_the_floor_when_bids_clear_high() {
+    let mut auction = ended_auction();
+    auction.min_raise = 150;
+    fill(&mut auction, 4 * CCT_UNIT, 50);
+    fill(&mut auction, 6 * CCT_UNIT, 40);
+
+    let clearing_price = calculate_final_clearing_price(&auction).unwrap();
+    assert_eq!(clearing_price, 40);
+    assert_eq!(calculate_settled_proceeds(&auction, clearing_price).unwrap(), 400);
+}
+
+#[test]
+fn auction_without_bids_settles_nothing() {
+    let mut auction = ended_auction();
+    auction.min_raise = 1;
+
+    // Clears at the end price, with nothing sold
+    let clearing_price = calculate_final_clearing_price(&auction).unwrap();
+    assert_eq!(clearing_price, 10);
+    assert_eq!(calculate_settled_proceeds(&auction, clearing_price).unwrap(), 0);
+}
@@ -0,0 +1,157 @@
+use dutch_auction::{calculate_current_price, PriceBreakpoint, PriceCurve, MAX_PRICE_BREAKPOINTS};
+use proptest::prelude::*;
+
+const START: i64 = 1_700_000_000;
+
+fn price(curve: &PriceCurve, start_price: u64, reserve_price: u64, duration: i64, t: i64) -> u64 {
+    calculate_current_price(
+        curve,
+        start_price,
+        reserve_price,
+        START,
+        START + duration,
+        START + t,
+    )
+    .unwrap()
+}
+
+fn piecewise(points: &[(u32, u64)]) -> PriceCurve {
+    let mut breakpoints = [PriceBreakpoint::default(); MAX_PRICE_BREAKPOINTS];
+    for (slot, &(offset_seconds, price)) in breakpoints.iter_mut().zip(points) {
+        *slot = PriceBreakpoint {
+            offset_seconds,
+            price,
+        };
+    }
+    PriceCurve::PiecewiseLinear {
+        breakpoint_count: points.len() as u8,
+        breakpoints,
+    }
+}
+
+/// Any valid curve for the given auction
+fn curves(
+    start_price: u64,
+    reserve_price: u64,
+    duration: i64,
+) -> impl Strategy<Value = PriceCurve> {
+    let piecewise_points = prop::collection::vec(
+        (1..duration.max(2), reserve_price..=start_price),
+        1..=MAX_PRICE_BREAKPOINTS,
+    )
+    .prop_map(move |mut points| {
+        // Increasing offsets, non-increasing prices
+        points.sort_by_key(|&(offset, _)| offset);
+        points.dedup_by_key(|&mut (offset, _)| offset);
+        let mut prices: Vec<u64> = points.iter().map(|&(_, price)| price).collect();
+        prices.sort_unstable_by(|a, b| b.cmp(a));
+
+        let points: Vec<(u32, u64)> = points
+            .iter()
+            .zip(prices)
+            .filter(|&(&(offset, _), _)| offset < duration)
+            .map(|(&(offset, _), price)| (offset as u32, price))
+            .collect();
+        if points.is_empty() {
+            PriceCurve::Linear
+        } else {
+            piecewise(&points)
+        }
+    });
+
+    prop_oneof![
+        Just(PriceCurve::Linear),
+        (1u32..100_000).prop_map(|half_life_seconds| PriceCurve::Exponential { half_life_seconds }),
+        (1u32..10_000, 1u64..1_000_000).prop_map(|(step_seconds, step_amount)| {
+            PriceCurve::Stepwise {
+                step_seconds,
+                step_amount,
+            }
+        }),
+        piecewise_points,
+    ]
+}
+
+fn auction_with_curve() -> impl Strategy<Value = (u64, u64, i64, PriceCurve)> {
+    (1u64..1_000_000, 1u64..1_000_000, 1i64..100_000).prop_flat_map(
+        |(reserve, spread, duration)| {
+            let start = reserve + spread;
+            (
+                Just(start),
+                Just(reserve),
+                Just(duration),
+                curves(start, reserve, duration),
+            )
+        },
+    )
+}
+
+proptest! {
+    #[test]
+    fn every_curve_decays_from_start_to_reserve(
+        (start_price, reserve_price, duration, curve) in auction_with_curve(),
+        samples in prop::collection::vec(0i64..100_000, 1..20),
+    ) {
+        prop_assert!(auction_pricing::Curve::from(curve).is_valid(
+            start_price,
+            reserve_price,
+            duration
+        ));
+        prop_assert_eq!(price(&curve, start_price, reserve_price, duration, 0), start_price);
+        prop_assert_eq!(
+            price(&curve, start_price, reserve_price, duration, duration),
+            reserve_price
+        );
+
+        let mut times: Vec<i64> = samples.iter().map(|t| t % (duration + 1)).collect();
+        times.sort_unstable();
+
+        let mut previous = start_price;
+        for t in times {
+            let current = price(&curve, start_price, reserve_price, duration, t);
+            prop_assert!(current <= previous);
+            prop_assert!(current >= reserve_price);
+            previous = current;
+        }
+    }
+}
+
+#[test]
+fn exponential_halves_distance_to_reserve_each_half_life() {
+    let curve = PriceCurve::Exponential {
+        half_life_seconds: 100,
+    };
+
+    assert_eq!(price(&curve, 1_010, 10, 1_000, 100), 510);
+    assert_eq!(price(&curve, 1_010, 10, 1_000, 200), 260);
+    assert_eq!(price(&curve, 1_010, 10, 1_000, 150), 385);
+}
+
+#[test]
+fn piecewise_interpolates_between_breakpoints() {
+    let curve = piecewise(&[(100, 50), (300, 40)]);
+
+    assert_eq!(price(&curve, 100, 10, 1_000, 50), 75);
+    assert_eq!(price(&curve, 100, 10, 1_000, 100), 50);
+    assert_eq!(price(&curve, 100, 10, 1_000, 200), 45);
+    assert_eq!(price(&curve, 100, 10, 1_000, 650), 25);
+}
+
+#[test]
+fn invalid_curves_are_rejected() {
+    let check = |curve: PriceCurve| auction_pricing::Curve::from(curve).is_valid(100, 10, 1_000);
+
+    assert!(!check(PriceCurve::Exponential {
+        half_life_seconds: 0
+    }));
+    assert!(!check(PriceCurve::Stepwise {
+        step_seconds: 60,
+        step_amount: 0
+    }));
+    assert!(!check(piecewise(&[])));
+    assert!(!check(piecewise(&[(300, 50), (200, 40)])));
+    assert!(!check(piecewise(&[(100, 40), (200, 50)])));
+    assert!(!check(piecewise(&[(100, 5)])));
+    assert!(!check(piecewise(&[(1_000, 50)])));
+    assert!(check(piecewise(&[(100, 50), (200, 50)])));
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::Pubkey;
+use dutch_auction::{
+    configure_auction, Auction, AuctionKind, AuctionStatus, BidLimits, ExtensionPolicy, PriceCurve,
+};
+
+const NOW: i64 = 1_700_000_000;
+
+fn empty_auction() -> Auction {
+    Auction {
+        authority: Pubkey::new_unique(),
+        batch_number: 1,
+        series: Pubkey::default(),
+        total_tokens: 0,
+        tokens_remaining: 0,
+        start_price: 0,
+        current_price: 0,
+        reserve_price: 0,
+        price_curve: PriceCurve::Linear,
+        bid_limits: BidLimits::default(),
+        kind: AuctionKind::Dutch,
+        extension: ExtensionPolicy::default(),
+        min_raise: 0,
+        start_time: 0,
+        end_time: 0,
+        curve_end_time: 0,
+        status: AuctionStatus::Active,
+        total_raised: 0,
+        participant_count: 0,
+        usdc_mint: Pubkey::new_unique(),
+        treasury: Pubkey::new_unique(),
+        proceeds_withdrawn: 0,
+        lowest_fill_price: 0,
+        clearing_price: 0,
+        unsold_tokens: 0,
+    }
+}
+
+fn configure(auction: &mut Auction, start_time: i64, start_price: u64) -> anchor_lang::Result<()> {
+    configure_auction(
+        auction,
+        NOW,
+        1_000,
+        start_price,
+        10,
+        start_time,
+        3_600,
+        PriceCurve::Linear,
+        BidLimits::default(),
+        ExtensionPolicy::default(),
+        0,
+    )
+}
+
+#[test]
+fn future_start_time_schedules_the_batch() {
+    let mut auction = empty_auction();
+    configure(&mut auction, NOW + 86_400, 100).unwrap();
+
+    assert_eq!(auction.status, AuctionStatus::Scheduled);
+    assert_eq!(auction.start_time, NOW + 86_400);
+    assert_eq!(auction.end_time, NOW + 86_400 + 3_600);
+    assert_eq!(auction.curve_end_time, auction.end_time);
+}
+
+#[test]
+fn past_or_zero_start_time_opens_immediately() {
+    for start_time in [0, NOW - 60, NOW] {
+        let mut auction = empty_auction();
+        configure(&mut auction, start_time, 100).unwrap();
+
+        assert_eq!(auction.status, AuctionStatus::Active);
+        assert_eq!(auction.start_time, NOW);
+        assert_eq!(auction.end_time, NOW + 3_600);
+    }
+}
+
+#[test]
+fn reconfiguring_replaces_the_schedule_and_prices() {
+    let mut auction = empty_auction();
+    configure(&mut auction, NOW + 86_400, 100).unwrap();
+    configure(&mut auction, NOW + 2 * 86_400, 80).unwrap();
+
+    assert_eq!(auction.status, AuctionStatus::Scheduled);
+    assert_eq!(auction.start_time, NOW + 2 * 86_400);
+    assert_eq!(auction.start_price, 80);
+    assert_eq!(auction.current_price, 80);
+    assert_eq!(auction.tokens_remaining, 1_000);
+}
+
+#[test]
+fn invalid_parameters_leave_the_auction_untouched() {
+    let mut auction = empty_auction();
+    configure(&mut auction, NOW + 86_400, 100).unwrap();
+
+    // Start price must stay above the reserve
+    assert!(configure(&mut auction, NOW + 2 * 86_400, 10).is_err());
+    assert_eq!(auction.start_time, NOW + 86_400);
+    assert_eq!(auction.start_price, 100);
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::Pubkey;
+use auction_pricing::{pro_rata_fill, sealed_clearing};
+use dutch_auction::{sealed_bid_commitment, PriceLevel, SealedBook, MAX_SEALED_PRICE_LEVELS};
+
+fn sealed_book(bids: &[(u64, u64)]) -> SealedBook {
+    let mut book = SealedBook {
+        auction: Pubkey::new_unique(),
+        levels: Vec::new(),
+        marginal_demand: 0,
+        marginal_supply: 0,
+    };
+    for &(price, tokens) in bids {
+        book.add_demand(price, tokens).unwrap();
+    }
+    book
+}
+
+fn levels(book: &SealedBook) -> impl Iterator<Item = (u64, u64)> + '_ {
+    book.levels.iter().map(|level| (level.price, level.tokens))
+}
+
+#[test]
+fn book_keeps_levels_sorted_and_merges_equal_prices() {
+    let book = sealed_book(&[(20, 100), (50, 10), (20, 5), (35, 40)]);
+
+    assert_eq!(
+        book.levels,
+        vec![
+            PriceLevel { price: 50, tokens: 10 },
+            PriceLevel { price: 35, tokens: 40 },
+            PriceLevel { price: 20, tokens: 105 },
+        ]
+    );
+}
+
+#[test]
+fn book_rejects_more_levels_than_fit_the_account() {
+    let mut book = sealed_book(&[]);
+    for price in 1..=MAX_SEALED_PRICE_LEVELS as u64 {
+        book.add_demand(price, 1).unwrap();
+    }
+
+    assert!(!book.has_room_for(1_000));
+    assert!(book.add_demand(1_000, 1).is_err());
+    // Existing prices still accept demand
+    assert!(book.has_room_for(1));
+    assert!(book.add_demand(1, 1).is_ok());
+}
+
+#[test]
+fn oversubscribed_margin_is_shared_pro_rata() {
+    // 1,000 tokens: 600 clear at 50, leaving 400 for 800 demanded at 30
+    let book = sealed_book(&[(50, 600), (30, 500), (30, 300), (10, 1_000)]);
+    let clearing = sealed_clearing(levels(&book), 1_000, 5).unwrap();
+
+    assert_eq!(clearing.clearing_price, 30);
+    assert_eq!(clearing.tokens_allocated, 1_000);
+    assert_eq!(pro_rata_fill(600, 50, &clearing), Some(600));
+    assert_eq!(pro_rata_fill(500, 30, &clearing), Some(250));
+    assert_eq!(pro_rata_fill(300, 30, &clearing), Some(150));
+    assert_eq!(pro_rata_fill(1_000, 10, &clearing), Some(0));
+}
+
+#[test]
+fn undersubscribed_auction_clears_at_the_lowest_revealed_price() {
+    let book = sealed_book(&[(40, 100), (25, 200)]);
+    let clearing = sealed_clearing(levels(&book), 1_000, 5).unwrap();
+
+    assert_eq!(clearing.clearing_price, 25);
+    assert_eq!(clearing.tokens_allocated, 300);
+    assert_eq!(pro_rata_fill(100, 40, &clearing), Some(100));
+    assert_eq!(pro_rata_fill(200, 25, &clearing), Some(200));
+
+    let empty = sealed_clearing(levels(&sealed_book(&[])), 1_000, 5).unwrap();
+    assert_eq!(empty.clearing_price, 5);
+    assert_eq!(empty.tokens_allocated, 0);
+}
+
+#[test]
+fn commitment_binds_bid_salt_and_bidder() {
+    let bidder = Pubkey::new_unique();
+    let salt = [7u8; 32];
+    let commitment = sealed_bid_commitment(&bidder, 100, 25, &salt);
+
+    assert_eq!(commitment, sealed_bid_commitment(&bidder, 100, 25, &salt));
+    assert_ne!(commitment, sealed_bid_commitment(&bidder, 100, 26, &salt));
+    assert_ne!(commitment, sealed_bid_commitment(&bidder, 101, 25, &salt));
+    assert_ne!(commitment, sealed_bid_commitment(&bidder, 100, 25, &[8u8; 32]));
+    assert_ne!(
+        commitment,
+        sealed_bid_commitment(&Pubkey::new_unique(), 100, 25, &salt)
+    );
+}
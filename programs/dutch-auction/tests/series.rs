@@ -0,0 +1,59 @@
+use dutch_auction::{
+    calculate_series_start_price, BidLimits, ExtensionPolicy, PriceCurve, SeriesTemplate,
+};
+
+fn template(start_price: u64, reserve_price: u64, price_curve: PriceCurve) -> SeriesTemplate {
+    SeriesTemplate {
+        start_price,
+        reserve_price,
+        duration_seconds: 3_600,
+        price_curve,
+        bid_limits: BidLimits::default(),
+        extension: ExtensionPolicy::default(),
+        min_raise: 0,
+    }
+}
+
+#[test]
+fn fixed_series_always_opens_at_the_template_price() {
+    let linear = template(100, 10, PriceCurve::Linear);
+
+    assert_eq!(calculate_series_start_price(&linear, None, Some(40)).unwrap(), 100);
+    // The first batch has no clearing price to adapt to
+    assert_eq!(calculate_series_start_price(&linear, Some(2_000), None).unwrap(), 100);
+}
+
+#[test]
+fn adaptive_start_marks_up_the_previous_clearing_price() {
+    let linear = template(100, 10, PriceCurve::Linear);
+
+    // 40 * 1.25
+    assert_eq!(calculate_series_start_price(&linear, Some(2_500), Some(40)).unwrap(), 50);
+    assert_eq!(calculate_series_start_price(&linear, Some(0), Some(40)).unwrap(), 40);
+}
+
+#[test]
+fn adaptive_start_stays_within_the_template_band() {
+    let linear = template(100, 10, PriceCurve::Linear);
+
+    // Never above the template start price
+    assert_eq!(calculate_series_start_price(&linear, Some(5_000), Some(90)).unwrap(), 100);
+    // Never at or below the reserve, so the curve still descends
+    assert_eq!(calculate_series_start_price(&linear, Some(0), Some(10)).unwrap(), 11);
+    assert_eq!(calculate_series_start_price(&linear, Some(0), Some(0)).unwrap(), 11);
+}
+
+#[test]
+fn series_template_must_fit_a_batch() {
+    assert!(template(100, 10, PriceCurve::Linear).is_valid(1_000));
+
+    assert!(!template(10, 10, PriceCurve::Linear).is_valid(1_000));
+
+    let mut zero_duration = template(100, 10, PriceCurve::Linear);
+    zero_duration.duration_seconds = 0;
+    assert!(!zero_duration.is_valid(1_000));
+
+    let mut oversized_min_bid = template(100, 10, PriceCurve::Linear);
+    oversized_min_bid.bid_limits.min_bid_tokens = 1_001;
+    assert!(!oversized_min_bid.is_valid(1_000));
+}